@@ -1,11 +1,12 @@
 use bevy::prelude::*;
 
 pub mod hide;
+pub mod shield;
 
 pub struct AbilityPlugin;
 
 impl Plugin for AbilityPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(hide::HidePlugin);
+        app.add_plugins((hide::HidePlugin, shield::ShieldPlugin));
     }
 }
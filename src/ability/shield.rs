@@ -0,0 +1,53 @@
+use bevy::color::palettes::css::*;
+use bevy::prelude::*;
+
+use crate::configuration::Configuration;
+use crate::physic::collision::collider::Collider;
+
+pub struct ShieldPlugin;
+
+#[derive(Component)]
+pub struct Shield {
+    pub hits: u32,
+    pub timer: Timer,
+}
+
+impl Plugin for ShieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (check_shield, render_shield).chain());
+    }
+}
+
+impl Shield {
+    pub fn new(configuration: &Configuration) -> Shield {
+        Shield {
+            hits: configuration.shield_hits,
+            timer: Timer::from_seconds(configuration.shield_duration, TimerMode::Once),
+        }
+    }
+
+    pub fn absorb(&mut self) -> bool {
+        if self.hits == 0 {
+            return false;
+        }
+        self.hits -= 1;
+        true
+    }
+}
+
+fn check_shield(mut commands: Commands, mut query: Query<(Entity, &mut Shield)>, time: Res<Time>) {
+    for (entity, mut shield) in query.iter_mut() {
+        shield.timer.tick(time.delta());
+        if shield.timer.finished() || shield.hits == 0 {
+            commands.entity(entity).remove::<Shield>();
+        }
+    }
+}
+
+fn render_shield(mut gizmos: Gizmos, query: Query<(&Collider, &Shield)>) {
+    for (collider, shield) in query.iter() {
+        let radius = collider.width().max(collider.height()) / 2. + 10.;
+        let color = AQUA.with_alpha(1. - shield.timer.fraction() * 0.7);
+        gizmos.circle_2d(collider.center(), radius, color);
+    }
+}
@@ -15,24 +15,15 @@ pub struct Controls {
     pub second_movement: Movement,
 }
 
+#[derive(Clone)]
 pub struct Movement {
     pub forward: KeyCode,
     pub backward: KeyCode,
     pub right: KeyCode,
     pub left: KeyCode,
     pub shoot: KeyCode,
-}
-
-impl Clone for Movement {
-    fn clone(&self) -> Self {
-        Self {
-            forward: self.forward.clone(),
-            backward: self.backward.clone(),
-            right: self.right.clone(),
-            left: self.left.clone(),
-            shoot: self.shoot.clone(),
-        }
-    }
+    pub next_weapon: KeyCode,
+    pub previous_weapon: KeyCode,
 }
 
 impl Controls {
@@ -45,6 +36,8 @@ impl Controls {
                 right: KeyCode::KeyD,
                 left: KeyCode::KeyA,
                 shoot: KeyCode::Space,
+                next_weapon: KeyCode::KeyE,
+                previous_weapon: KeyCode::KeyQ,
             },
             second_movement: Movement {
                 forward: KeyCode::ArrowUp,
@@ -52,6 +45,8 @@ impl Controls {
                 right: KeyCode::ArrowRight,
                 left: KeyCode::ArrowLeft,
                 shoot: KeyCode::ShiftRight,
+                next_weapon: KeyCode::Period,
+                previous_weapon: KeyCode::Comma,
             },
         }
     }
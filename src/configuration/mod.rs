@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::enemy::difficulty::DifficultyProfile;
+
 pub mod controls;
 pub mod resolution;
 pub mod visibility;
@@ -19,30 +21,89 @@ impl Plugin for ConfigurationPlugin {
 
 #[derive(Resource)]
 pub struct Configuration {
-    pub bullet_speed: f32,
-    pub max_rocket_range: f32,
+    pub bank_shot_samples: u32,
+    pub bounce_spin_transfer: f32,
+    pub bullets_cancel: bool,
+    pub bullets_detonate_mines: bool,
+    pub chest_respawn_interval: f32,
+    pub default_weapon: String,
+    pub enemy_difficulties: Vec<DifficultyProfile>,
+    pub enemy_dodge_radius: f32,
+    pub enemy_preferred_distance: f32,
+    pub enemy_sight_range: f32,
+    pub enemy_stuck_time: f32,
+    pub enemy_waypoint_radius: f32,
+    pub explosion_knockback: f32,
+    pub impulse_decay: f32,
+    pub impulse_threshold: f32,
+    pub inventory_size: usize,
+    pub laser_duration: f32,
+    pub max_chests: usize,
+    pub mine_arming_delay: f32,
+    pub mine_blink_interval: f32,
+    pub mine_owner_immunity: bool,
     pub mine_visible_duration: u128,
+    pub mine_warning_duration: f32,
     pub move_speed: f32,
+    pub navigation_cell_size: f32,
+    pub navigation_rebuild_interval: f32,
+    pub placement_attempts: u32,
+    pub placement_margin: f32,
+    pub placement_min_distance: f32,
+    pub projectile_knockback: f32,
     pub rotation_speed: f32,
-    pub shoot_interval: u128,
-    pub shuriken_bounce_count: u32,
-    pub shuriken_speed: f32,
+    pub shield_chest_chance: f64,
+    pub shield_duration: f32,
+    pub shield_hits: u32,
+    pub shurikens_deflect_off_mines: bool,
     pub spawn_protection: f32,
+    pub tank_collision_knockback: f32,
+    pub tank_health: f32,
     pub tank_mine_location: Vec3,
     pub tank_shoot_location: Vec3,
 }
 
 fn setup_configuration(mut commands: Commands) {
     commands.insert_resource(Configuration {
-        bullet_speed: 1000.,
-        max_rocket_range: f32::MAX,
+        bank_shot_samples: 180,
+        bounce_spin_transfer: 0.01,
+        bullets_cancel: true,
+        bullets_detonate_mines: true,
+        chest_respawn_interval: 8.,
+        default_weapon: String::from("shuriken"),
+        // Bots are opt-in, one per profile, e.g. `vec![DifficultyProfile::Normal]`.
+        enemy_difficulties: Vec::new(),
+        enemy_dodge_radius: 60.,
+        enemy_preferred_distance: 250.,
+        enemy_sight_range: 700.,
+        enemy_stuck_time: 1.5,
+        enemy_waypoint_radius: 40.,
+        explosion_knockback: 900.,
+        impulse_decay: 6.,
+        impulse_threshold: 5.,
+        inventory_size: 3,
+        laser_duration: 0.3,
+        max_chests: 3,
+        mine_arming_delay: 1.5,
+        mine_blink_interval: 0.1,
+        mine_owner_immunity: true,
         mine_visible_duration: 5000,
+        mine_warning_duration: 0.6,
         move_speed: 200.,
+        navigation_cell_size: 20.,
+        navigation_rebuild_interval: 0.25,
+        placement_attempts: 100,
+        placement_margin: 20.,
+        placement_min_distance: 120.,
+        projectile_knockback: 600.,
         rotation_speed: 3.,
-        shoot_interval: 300,
-        shuriken_bounce_count: 3,
-        shuriken_speed: 1000.,
+        shield_chest_chance: 0.2,
+        shield_duration: 10.,
+        shield_hits: 3,
+        shurikens_deflect_off_mines: true,
         spawn_protection: 3.,
+        tank_collision_knockback: 250.,
+        tank_health: 100.,
         tank_mine_location: Vec3::new(0., -60., 0.),
         tank_shoot_location: Vec3::new(0., 60., 0.),
     })
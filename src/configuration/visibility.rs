@@ -12,6 +12,9 @@ impl Plugin for VisibilityPlugin {
     }
 }
 
+#[derive(Component)]
+pub struct DebugText;
+
 #[derive(States, Default, Debug, Hash, PartialEq, Eq, Clone, Copy)]
 pub enum DebugState {
     #[default]
@@ -21,6 +24,7 @@ pub enum DebugState {
 
 fn spawn_text(mut commands: Commands) {
     commands.spawn((
+        DebugText,
         Text::default(),
         Node {
             position_type: PositionType::Absolute,
@@ -31,7 +35,7 @@ fn spawn_text(mut commands: Commands) {
     ));
 }
 
-fn update_text(mut text: Single<&mut Text>, cur_state: Res<State<DebugState>>) {
+fn update_text(mut text: Single<&mut Text, With<DebugText>>, cur_state: Res<State<DebugState>>) {
     if !cur_state.is_changed() {
         return;
     }
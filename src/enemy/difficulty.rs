@@ -0,0 +1,50 @@
+#[derive(Clone)]
+pub struct Difficulty {
+    // Seconds between two decisions about where to go and what to shoot at.
+    pub reaction_time: f32,
+    // Largest angles in radians by which the aim misses the target, and by which
+    // the barrel may still be off when firing.
+    pub aim_error: f32,
+    pub aim_tolerance: f32,
+    // Fraction of the full tank rotation speed the controller may use.
+    pub turn_rate: f32,
+    // Seconds ahead in which incoming projectiles are dodged, zero to never dodge.
+    pub dodge_horizon: f32,
+}
+
+#[derive(Clone)]
+pub enum DifficultyProfile {
+    Easy,
+    Normal,
+    Hard,
+    Custom(Difficulty),
+}
+
+impl DifficultyProfile {
+    pub fn difficulty(&self) -> Difficulty {
+        match self {
+            DifficultyProfile::Easy => Difficulty {
+                reaction_time: 0.6,
+                aim_error: 0.25,
+                aim_tolerance: 0.08,
+                turn_rate: 0.5,
+                dodge_horizon: 0.,
+            },
+            DifficultyProfile::Normal => Difficulty {
+                reaction_time: 0.3,
+                aim_error: 0.1,
+                aim_tolerance: 0.04,
+                turn_rate: 0.8,
+                dodge_horizon: 0.4,
+            },
+            DifficultyProfile::Hard => Difficulty {
+                reaction_time: 0.1,
+                aim_error: 0.02,
+                aim_tolerance: 0.01,
+                turn_rate: 1.,
+                dodge_horizon: 1.,
+            },
+            DifficultyProfile::Custom(difficulty) => difficulty.clone(),
+        }
+    }
+}
@@ -1,9 +1,401 @@
+use std::f32::consts::FRAC_PI_4;
+
 use bevy::prelude::*;
+use rand::random_range;
+
+use crate::configuration::resolution::Resolution;
+use crate::configuration::Configuration;
+use crate::enemy::difficulty::Difficulty;
+use crate::environment::chest::Chest;
+use crate::environment::navigation::NavGrid;
+use crate::environment::spawn::spawn_tanks;
+use crate::environment::spawn::SpawnPoint;
+use crate::physic::bank_shot::BankShotSolver;
+use crate::physic::collision::collider::Collider;
+use crate::physic::collision::ray::Raycast;
+use crate::physic::solid::Solid;
+use crate::physic::velocity::Velocity;
+use crate::projectile::Projectile;
+use crate::tank::Tank;
+use crate::tank::TankBundle;
+use crate::weapon::definition::Weapons;
+use crate::weapon::inventory::Inventory;
+use crate::weapon::Magazine;
+use crate::weapon::Shot;
+use crate::weapon::Weapon;
+
+pub mod difficulty;
 
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
-    fn build(&self, app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_enemies).add_systems(
+            Update,
+            (
+                choose_goals,
+                plan_bank_shots.run_if(resource_exists::<Weapons>),
+                drive_enemies,
+                shoot_enemies.run_if(resource_exists::<Weapons>),
+            )
+                .chain()
+                .before(spawn_tanks),
+        );
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Goal {
+    pub position: Vec3,
+    pub attack: bool,
 }
+
 #[derive(Component)]
-pub struct Enemy {}
+pub struct Enemy {
+    difficulty: Difficulty,
+    goal: Option<Goal>,
+    waypoint: Option<Vec3>,
+    aimed: bool,
+    deciding: bool,
+    next_decision: f32,
+    last_shot: f32,
+    last_position: Vec3,
+    stuck: f32,
+}
+
+impl Enemy {
+    pub fn new(difficulty: Difficulty) -> Enemy {
+        Enemy {
+            difficulty,
+            goal: None,
+            waypoint: None,
+            aimed: false,
+            deciding: true,
+            next_decision: 0.,
+            last_shot: 0.,
+            last_position: Vec3::ZERO,
+            stuck: 0.,
+        }
+    }
+
+    fn miss(&self) -> Quat {
+        let aim_error = self.difficulty.aim_error;
+        Quat::from_rotation_z(random_range(-aim_error..=aim_error))
+    }
+}
+
+fn setup_enemies(
+    mut commands: Commands,
+    assets_server: Res<AssetServer>,
+    resolution: Res<Resolution>,
+    configuration: Res<Configuration>,
+) {
+    // Placeholder: bots reuse a player texture, grayed out, until they get their own art.
+    let enemy_texture = assets_server.load("player/tank_yellow.png");
+    for profile in configuration.enemy_difficulties.iter() {
+        // Only the scale matters, `spawn_tanks` moves new tanks to a spawn point.
+        let spawn_location = Transform::from_scale(Vec3::splat(resolution.tank_pixel_ratio));
+        commands.spawn((
+            Enemy::new(profile.difficulty()),
+            TankBundle::new(
+                spawn_location,
+                Sprite {
+                    color: Color::srgb(0.6, 0.6, 0.6),
+                    ..Sprite::from_image(enemy_texture.clone())
+                },
+                &configuration,
+            ),
+        ));
+    }
+}
+
+// Tanks are solids too, so only the other solids can block the view.
+fn can_see(
+    from: Vec3,
+    to: Vec3,
+    solid_query: &Query<&Collider, (With<Solid>, Without<Tank>)>,
+) -> bool {
+    let offset = (to - from).truncate();
+    let distance = offset.length();
+    let direction = offset.normalize_or_zero();
+    !solid_query.iter().any(|solid_c| {
+        solid_c
+            .raycast(from.truncate(), direction)
+            .is_some_and(|hit| hit.distance < distance)
+    })
+}
+
+// Enemies attack the nearest tank they can see, go for visible chests when
+// there is nobody to fight, and otherwise patrol between the spawn points.
+fn choose_goals(
+    mut enemy_query: Query<(Entity, &mut Enemy, &Transform)>,
+    tank_query: Query<(Entity, &Transform), With<Tank>>,
+    chest_query: Query<&Transform, With<Chest>>,
+    solid_query: Query<&Collider, (With<Solid>, Without<Tank>)>,
+    spawn_point_query: Query<&Transform, With<SpawnPoint>>,
+    nav_grid: Res<NavGrid>,
+    configuration: Res<Configuration>,
+) {
+    for (entity, mut enemy, transform) in enemy_query.iter_mut() {
+        if !enemy.deciding {
+            continue;
+        }
+        let position = transform.translation;
+        let visible = |target: Vec3| {
+            position.distance(target) <= configuration.enemy_sight_range
+                && can_see(position, target, &solid_query)
+        };
+        let nearest = |targets: Vec<Vec3>| {
+            targets
+                .into_iter()
+                .filter(|target| visible(*target))
+                .min_by(|first, second| {
+                    position
+                        .distance(*first)
+                        .total_cmp(&position.distance(*second))
+                })
+        };
+
+        let tanks = tank_query
+            .iter()
+            .filter(|(tank, _)| *tank != entity)
+            .map(|(_, tank)| tank.translation)
+            .collect();
+        if let Some(target) = nearest(tanks) {
+            enemy.goal = Some(Goal {
+                position: position + enemy.miss() * (target - position),
+                attack: true,
+            });
+            continue;
+        }
+
+        // Anything that is not shot at is reached along the navigation grid.
+        let travel = |target: Vec3| Goal {
+            position: nav_grid.next_waypoint(position, target).unwrap_or(target),
+            attack: false,
+        };
+
+        let chests = chest_query.iter().map(|chest| chest.translation).collect();
+        if let Some(target) = nearest(chests) {
+            enemy.goal = Some(travel(target));
+            continue;
+        }
+
+        if enemy.waypoint.is_some_and(|waypoint| {
+            waypoint.distance(position) <= configuration.enemy_waypoint_radius
+        }) {
+            enemy.waypoint = None;
+        }
+        if enemy.waypoint.is_none() {
+            let spawn_points: Vec<Vec3> = spawn_point_query
+                .iter()
+                .map(|spawn_point| spawn_point.translation)
+                .collect();
+            if !spawn_points.is_empty() {
+                enemy.waypoint = Some(spawn_points[random_range(0..spawn_points.len())]);
+            }
+        }
+        enemy.goal = enemy.waypoint.map(travel);
+    }
+}
+
+// Tanks out of sight can still be hit with bouncing projectiles, so enemies
+// look for a bank shot before giving up on them.
+fn plan_bank_shots(
+    mut enemy_query: Query<(Entity, &mut Enemy, &Transform, &Weapon)>,
+    tank_query: Query<(Entity, &Transform, &Collider), With<Tank>>,
+    solid_query: Query<(Entity, &Collider), With<Solid>>,
+    configuration: Res<Configuration>,
+    weapons: Res<Weapons>,
+) {
+    for (entity, mut enemy, transform, weapon) in enemy_query.iter_mut() {
+        if !enemy.deciding || enemy.goal.is_some_and(|goal| goal.attack) {
+            continue;
+        }
+        let Some(definition) = weapons.get(weapon) else {
+            continue;
+        };
+        if definition.bounce_count == 0 {
+            continue;
+        }
+
+        let position = transform.translation;
+        let Some((target, target_transform, target_c)) = tank_query
+            .iter()
+            .filter(|(tank, _, _)| *tank != entity)
+            .filter(|(_, tank, _)| {
+                tank.translation.distance(position) <= configuration.enemy_sight_range
+            })
+            .min_by(|(_, first, _), (_, second, _)| {
+                first
+                    .translation
+                    .distance(position)
+                    .total_cmp(&second.translation.distance(position))
+            })
+        else {
+            continue;
+        };
+
+        let solids: Vec<&Collider> = solid_query
+            .iter()
+            .filter(|(solid, _)| *solid != entity && *solid != target)
+            .map(|(_, solid_c)| solid_c)
+            .collect();
+        let solver = BankShotSolver {
+            solids: &solids,
+            samples: configuration.bank_shot_samples,
+            max_distance: definition
+                .reach()
+                .unwrap_or(configuration.enemy_sight_range * 2.),
+        };
+        let shot = solver.solve(
+            transform,
+            configuration.tank_shoot_location,
+            target_transform.translation.truncate(),
+            target_c.width().min(target_c.height()) / 2.,
+            definition.radius,
+            definition.bounce_count,
+        );
+        if let Some(shot) = shot {
+            // A point just in front of the tank makes it turn into the firing
+            // direction without driving anywhere.
+            enemy.goal = Some(Goal {
+                position: position + enemy.miss() * shot.rotation * Vec3::Y,
+                attack: true,
+            });
+        }
+    }
+}
+
+fn drive_enemies(
+    mut enemy_query: Query<(Entity, &mut Enemy, &mut Tank, &mut Transform)>,
+    projectile_query: Query<(&Projectile, &Transform, &Velocity), Without<Enemy>>,
+    configuration: Res<Configuration>,
+    time: Res<Time>,
+) {
+    for (entity, mut enemy, mut tank, mut transform) in enemy_query.iter_mut() {
+        enemy.next_decision -= time.delta_secs();
+        enemy.deciding = enemy.next_decision <= 0.;
+        if enemy.deciding {
+            enemy.next_decision = enemy.difficulty.reaction_time;
+        }
+
+        let forward = transform.rotation * Vec3::Y;
+        let mut movement = 0.;
+        let mut rotation = 0.;
+        enemy.aimed = false;
+        if let Some(goal) = enemy.goal {
+            let offset = (goal.position - transform.translation).truncate();
+            if offset.length_squared() >= f32::EPSILON {
+                let angle = forward.truncate().angle_to(offset);
+                rotation = (angle / FRAC_PI_4).clamp(-1., 1.);
+                if !(goal.attack && offset.length() <= configuration.enemy_preferred_distance
+                    || angle.abs() > FRAC_PI_4)
+                {
+                    movement = 1.;
+                }
+                enemy.aimed = goal.attack && angle.abs() <= enemy.difficulty.aim_tolerance;
+            }
+        }
+        if let Some(dodge) = dodge(
+            entity,
+            transform.translation,
+            forward,
+            &enemy.difficulty,
+            &projectile_query,
+            &configuration,
+        ) {
+            movement = dodge;
+        }
+        let turn_rate = enemy.difficulty.turn_rate;
+        rotation = rotation.clamp(-turn_rate, turn_rate);
+
+        // A wall in the way keeps reverting the tank, so patrols pick another waypoint.
+        if movement > 0. && transform.translation.distance(enemy.last_position) < f32::EPSILON {
+            enemy.stuck += time.delta_secs();
+        } else {
+            enemy.stuck = 0.;
+        }
+        if enemy.stuck >= configuration.enemy_stuck_time {
+            enemy.stuck = 0.;
+            enemy.waypoint = None;
+        }
+        enemy.last_position = transform.translation;
+
+        tank.drive(
+            &mut transform,
+            movement,
+            rotation,
+            &configuration,
+            time.delta_secs(),
+        );
+    }
+}
+
+// Predicts where each enemy projectile passes the tank closest within the
+// difficulty's horizon and drives forward or backward away from the first one
+// that would hit.
+fn dodge(
+    enemy: Entity,
+    position: Vec3,
+    forward: Vec3,
+    difficulty: &Difficulty,
+    projectile_query: &Query<(&Projectile, &Transform, &Velocity), Without<Enemy>>,
+    configuration: &Configuration,
+) -> Option<f32> {
+    if difficulty.dodge_horizon <= 0. {
+        return None;
+    }
+    projectile_query
+        .iter()
+        .filter(|(projectile, _, _)| projectile.owner != enemy)
+        .filter_map(|(_, transform, velocity)| {
+            let relative = transform.translation - position;
+            let speed_squared = velocity.length_squared();
+            if speed_squared < f32::EPSILON {
+                return None;
+            }
+            let time = -relative.dot(**velocity) / speed_squared;
+            if !(0. ..=difficulty.dodge_horizon).contains(&time) {
+                return None;
+            }
+            let miss = relative + **velocity * time;
+            (miss.length() <= configuration.enemy_dodge_radius).then_some((time, miss))
+        })
+        .min_by(|(first, _), (second, _)| first.total_cmp(second))
+        .map(|(_, miss)| if miss.dot(forward) > 0. { -1. } else { 1. })
+}
+
+fn shoot_enemies(
+    mut commands: Commands,
+    mut enemy_query: Query<(
+        Entity,
+        &mut Enemy,
+        &Weapon,
+        &mut Inventory,
+        Option<&Magazine>,
+    )>,
+    shot_query: Query<&Shot>,
+    weapons: Res<Weapons>,
+    time: Res<Time>,
+) {
+    for (entity, mut enemy, weapon, mut inventory, magazine) in enemy_query.iter_mut() {
+        // Picked up weapons are used right away instead of being kept in reserve.
+        if inventory.active() == 0 && inventory.slots().len() > 1 {
+            inventory.next(magazine);
+            inventory.equip(entity, &mut commands);
+            continue;
+        }
+
+        let Some(definition) = weapons.get(weapon) else {
+            continue;
+        };
+        if !enemy.aimed || time.elapsed_secs() - enemy.last_shot <= definition.cooldown {
+            continue;
+        }
+        let live_projectiles = Shot::count(entity, weapon, &shot_query);
+        if definition.shoot(entity, live_projectiles, &mut commands) {
+            enemy.last_shot = time.elapsed_secs();
+        }
+    }
+}
@@ -1,26 +1,80 @@
 use bevy::prelude::*;
+use rand::random_bool;
+use rand::random_range;
 
+use crate::ability::shield::Shield;
 use crate::configuration::resolution::Resolution;
-use crate::environment::random::get_random_position;
+use crate::configuration::Configuration;
+use crate::environment::map::Map;
+use crate::environment::placement::Placement;
 use crate::physic::collision::collider::Collider;
 use crate::physic::collision::polygon::PolygonCollider;
 use crate::physic::collision::Collision;
-use crate::physic::solid::Solid;
-use crate::player::Player;
+use crate::tank::Killed;
+use crate::tank::Tank;
+use crate::weapon::definition::Weapons;
+use crate::weapon::inventory::Inventory;
+use crate::weapon::Magazine;
 use crate::weapon::Weapon;
 
 pub struct ChestPlugin;
 
 impl Plugin for ChestPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_chest)
-            .add_systems(Update, collect);
+        app.add_systems(
+            Update,
+            (
+                spawn_chest.run_if(resource_exists::<Weapons>.and(chest_due)),
+                collect,
+                drop_weapons.run_if(resource_exists::<Weapons>),
+            ),
+        );
+    }
+}
+
+#[derive(Clone)]
+pub enum Loot {
+    Shield,
+    Weapon { weapon: Weapon, ammo: Option<u32> },
+}
+
+impl Loot {
+    pub fn random(configuration: &Res<Configuration>, weapons: &Res<Weapons>) -> Loot {
+        if random_bool(configuration.shield_chest_chance) {
+            return Loot::Shield;
+        }
+        match weapons.random() {
+            Some(weapon) => {
+                let ammo = weapons.get(&weapon).and_then(|definition| definition.ammo);
+                Loot::Weapon { weapon, ammo }
+            }
+            None => Loot::Shield,
+        }
+    }
+
+    pub fn get_asset_name(&self, weapons: &Weapons) -> String {
+        match self {
+            Loot::Shield => "environment/chest_shield.png".to_string(),
+            Loot::Weapon { weapon, .. } => weapons
+                .get(weapon)
+                .map(|definition| definition.chest_texture.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn get_color(&self, weapons: &Weapons) -> Color {
+        match self {
+            Loot::Shield => Color::WHITE,
+            Loot::Weapon { weapon, .. } => weapons
+                .get(weapon)
+                .map_or(Color::WHITE, |definition| definition.chest_color()),
+        }
     }
 }
 
 #[derive(Component)]
 pub struct Chest {
-    weapon: Weapon,
+    loot: Loot,
 }
 
 #[derive(Bundle)]
@@ -32,49 +86,139 @@ pub struct ChestBundle {
 
 impl ChestBundle {
     pub fn new(
-        solid: Query<&Collider, With<Solid>>,
+        loot: Loot,
+        position: Vec3,
         assets_server: &Res<AssetServer>,
         resolution: &Res<Resolution>,
+        weapons: &Res<Weapons>,
     ) -> ChestBundle {
-        let weapon = Weapon::random();
-        let chest_texture = assets_server.load(weapon.get_asset_name());
-        let collider = PolygonCollider::new(Box::new([
-            Vec2::new(-15., 15.),
-            Vec2::new(15., 15.),
-            Vec2::new(15., -15.),
-            Vec2::new(-15., -15.),
-        ]));
-        let position = get_random_position(Collider::Polygon(collider.clone()), solid, resolution);
+        let chest_texture = assets_server.load(loot.get_asset_name(weapons));
+        let chest_color = loot.get_color(weapons);
+        let collider = ChestBundle::collider();
         let spawn_location = Transform::from_translation(position)
             .with_scale(Vec3::splat(resolution.chest_pixel_ratio));
         ChestBundle {
-            chest: Chest { weapon },
+            chest: Chest { loot },
             collider: Collision::new(Collider::Polygon(collider), spawn_location),
-            sprite: Sprite::from_image(chest_texture),
+            sprite: Sprite {
+                color: chest_color,
+                ..Sprite::from_image(chest_texture)
+            },
         }
     }
+
+    fn collider() -> PolygonCollider {
+        PolygonCollider::new(Box::new([
+            Vec2::new(-15., 15.),
+            Vec2::new(15., 15.),
+            Vec2::new(15., -15.),
+            Vec2::new(-15., -15.),
+        ]))
+    }
+}
+
+// The timer only runs while there is room for another chest, so a collected
+// chest is replaced a full interval later. It starts out finished so the first
+// chest appears right away.
+fn chest_due(
+    chest_query: Query<(), With<Chest>>,
+    mut timer: Local<Timer>,
+    configuration: Res<Configuration>,
+    time: Res<Time>,
+) -> bool {
+    if chest_query.iter().count() >= configuration.max_chests {
+        return false;
+    }
+    timer.tick(time.delta());
+    if !timer.finished() {
+        return false;
+    }
+    *timer = Timer::from_seconds(configuration.chest_respawn_interval, TimerMode::Once);
+    true
 }
 
 fn spawn_chest(
-    solid: Query<&Collider, With<Solid>>,
+    placement: Placement,
     mut commands: Commands,
     assets_server: Res<AssetServer>,
+    configuration: Res<Configuration>,
+    map: Res<Map>,
     resolution: Res<Resolution>,
+    weapons: Res<Weapons>,
 ) {
-    commands.spawn(ChestBundle::new(solid, &assets_server, &resolution));
+    let area = if map.chest_zones.is_empty() {
+        placement.arena()
+    } else {
+        map.chest_zones[random_range(0..map.chest_zones.len())]
+    };
+    let collider = Collider::Polygon(ChestBundle::collider());
+    let position = match placement.find_position(&collider, area, &[], &[]) {
+        Ok(position) => position,
+        Err(error) => {
+            warn!("Could not place a chest: {error:?}");
+            return;
+        }
+    };
+    commands.spawn(ChestBundle::new(
+        Loot::random(&configuration, &weapons),
+        position,
+        &assets_server,
+        &resolution,
+        &weapons,
+    ));
 }
 
 fn collect(
     mut commands: Commands,
     chest_query: Query<(Entity, &Chest, &Collider)>,
-    mut player_query: Query<(&mut Weapon, &Collider), With<Player>>,
+    mut tank_query: Query<(Entity, &Collider, &mut Inventory, Option<&Magazine>), With<Tank>>,
+    configuration: Res<Configuration>,
 ) {
-    for (mut weapon, player_c) in player_query.iter_mut() {
+    for (tank, tank_c, mut inventory, magazine) in tank_query.iter_mut() {
         for (entity, chest, chest_c) in chest_query.iter() {
-            if player_c.intersects(chest_c) {
-                *weapon = chest.weapon.clone();
+            if tank_c.intersects(chest_c) {
+                match &chest.loot {
+                    Loot::Shield => {
+                        commands.entity(tank).insert(Shield::new(&configuration));
+                    }
+                    Loot::Weapon { weapon, ammo } => {
+                        if inventory.add(weapon.clone(), *ammo, magazine) {
+                            inventory.equip(tank, &mut commands);
+                        }
+                    }
+                }
                 commands.entity(entity).despawn();
             }
         }
     }
 }
+
+fn drop_weapons(
+    mut commands: Commands,
+    mut kills: EventReader<Killed>,
+    mut tank_query: Query<(&mut Inventory, Option<&Magazine>)>,
+    assets_server: Res<AssetServer>,
+    resolution: Res<Resolution>,
+    weapons: Res<Weapons>,
+) {
+    for killed in kills.read() {
+        let Ok((mut inventory, magazine)) = tank_query.get_mut(killed.tank) else {
+            continue;
+        };
+        let Some(slot) = inventory.take_active(magazine) else {
+            continue;
+        };
+        inventory.equip(killed.tank, &mut commands);
+        let loot = Loot::Weapon {
+            weapon: slot.weapon,
+            ammo: slot.ammo,
+        };
+        commands.spawn(ChestBundle::new(
+            loot,
+            killed.position,
+            &assets_server,
+            &resolution,
+            &weapons,
+        ));
+    }
+}
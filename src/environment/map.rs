@@ -0,0 +1,163 @@
+use std::f32::consts::FRAC_PI_2;
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+pub struct MapPlugin;
+
+impl Plugin for MapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreStartup, setup_map);
+    }
+}
+
+#[derive(Resource)]
+pub struct Map {
+    pub walls: Vec<WallDefinition>,
+    pub chest_zones: Vec<Rect>,
+    pub spawn_points: Vec<Transform>,
+}
+
+#[derive(Clone)]
+pub struct WallDefinition {
+    pub vertices: Box<[Vec2]>,
+    pub transform: Transform,
+    pub path: Option<WallPath>,
+    pub durability: Option<f32>,
+}
+
+#[derive(Clone)]
+pub enum WallPath {
+    // Moves back and forth between the spawn location and `target`.
+    PingPong { target: Vec3, speed: f32 },
+    // Orbits `center` without changing its own orientation.
+    Circular { center: Vec3, angular_speed: f32 },
+    // Rotates around `pivot`, turning with the rotation.
+    Rotating { pivot: Vec3, angular_speed: f32 },
+}
+
+impl WallPath {
+    pub fn transform_at(&self, origin: &Transform, elapsed: f32) -> Transform {
+        match self {
+            WallPath::PingPong { target, speed } => {
+                let distance = origin.translation.distance(*target);
+                if distance <= 0. {
+                    return *origin;
+                }
+                let phase = (elapsed * speed / distance) % 2.;
+                let progress = if phase > 1. { 2. - phase } else { phase };
+                origin.with_translation(origin.translation.lerp(*target, progress))
+            }
+            WallPath::Circular {
+                center,
+                angular_speed,
+            } => {
+                let rotation = Quat::from_rotation_z(angular_speed * elapsed);
+                origin.with_translation(center + rotation * (origin.translation - center))
+            }
+            WallPath::Rotating {
+                pivot,
+                angular_speed,
+            } => {
+                let rotation = Quat::from_rotation_z(angular_speed * elapsed);
+                origin
+                    .with_translation(pivot + rotation * (origin.translation - pivot))
+                    .with_rotation(rotation * origin.rotation)
+            }
+        }
+    }
+}
+
+fn setup_map(mut commands: Commands) {
+    commands.insert_resource(Map {
+        walls: vec![
+            WallDefinition {
+                vertices: Box::new([
+                    Vec2::new(0., 20.),
+                    Vec2::new(20., 120.),
+                    Vec2::new(120., 100.),
+                    Vec2::new(100., 0.),
+                ]),
+                transform: Transform::from_xyz(100., 100., 0.),
+                path: None,
+                durability: None,
+            },
+            WallDefinition {
+                vertices: Box::new([
+                    Vec2::new(-10., -60.),
+                    Vec2::new(-10., 60.),
+                    Vec2::new(10., 60.),
+                    Vec2::new(10., -60.),
+                ]),
+                transform: Transform::from_xyz(-400., 200., 0.),
+                path: Some(WallPath::PingPong {
+                    target: Vec3::new(-400., -200., 0.),
+                    speed: 100.,
+                }),
+                durability: None,
+            },
+            WallDefinition {
+                vertices: Box::new([
+                    Vec2::new(-80., -10.),
+                    Vec2::new(-80., 10.),
+                    Vec2::new(80., 10.),
+                    Vec2::new(80., -10.),
+                ]),
+                transform: Transform::from_xyz(350., -200., 0.),
+                path: Some(WallPath::Rotating {
+                    pivot: Vec3::new(350., -200., 0.),
+                    angular_speed: 1.,
+                }),
+                durability: None,
+            },
+            WallDefinition {
+                vertices: Box::new([
+                    Vec2::new(-20., -20.),
+                    Vec2::new(-20., 20.),
+                    Vec2::new(20., 20.),
+                    Vec2::new(20., -20.),
+                ]),
+                transform: Transform::from_xyz(480., 200., 0.),
+                path: Some(WallPath::Circular {
+                    center: Vec3::new(400., 200., 0.),
+                    angular_speed: 0.8,
+                }),
+                durability: None,
+            },
+            WallDefinition {
+                vertices: Box::new([
+                    Vec2::new(-30., -30.),
+                    Vec2::new(-30., 30.),
+                    Vec2::new(30., 30.),
+                    Vec2::new(30., -30.),
+                ]),
+                transform: Transform::from_xyz(-150., -200., 0.),
+                path: None,
+                durability: Some(600.),
+            },
+            WallDefinition {
+                vertices: Box::new([
+                    Vec2::new(-30., -30.),
+                    Vec2::new(-30., 30.),
+                    Vec2::new(30., 30.),
+                    Vec2::new(30., -30.),
+                ]),
+                transform: Transform::from_xyz(-90., -200., 0.),
+                path: None,
+                durability: Some(600.),
+            },
+        ],
+        chest_zones: vec![
+            Rect::new(-300., -100., 300., 100.),
+            Rect::new(150., -350., 550., -250.),
+            Rect::new(-550., 250., -150., 350.),
+        ],
+        spawn_points: vec![
+            Transform::from_xyz(-550., -320., 0.),
+            Transform::from_xyz(550., 320., 0.).with_rotation(Quat::from_rotation_z(PI)),
+            Transform::from_xyz(-550., 320., 0.).with_rotation(Quat::from_rotation_z(-FRAC_PI_2)),
+            Transform::from_xyz(550., -320., 0.).with_rotation(Quat::from_rotation_z(FRAC_PI_2)),
+            Transform::from_xyz(0., 0., 0.),
+        ],
+    });
+}
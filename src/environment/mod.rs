@@ -1,13 +1,22 @@
 use bevy::prelude::*;
 
 pub mod chest;
-mod random;
+pub mod map;
+pub mod navigation;
+pub mod placement;
+pub mod spawn;
 pub mod wall;
 
 pub struct EnvironmentPlugin;
 
 impl Plugin for EnvironmentPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((chest::ChestPlugin, wall::WallPlugin));
+        app.add_plugins((
+            chest::ChestPlugin,
+            map::MapPlugin,
+            navigation::NavigationPlugin,
+            spawn::SpawnPlugin,
+            wall::WallPlugin,
+        ));
     }
 }
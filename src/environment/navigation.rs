@@ -0,0 +1,344 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::configuration::resolution::Resolution;
+use crate::configuration::Configuration;
+use crate::environment::wall::Wall;
+use crate::physic::collision::circle::CircleCollider;
+use crate::physic::collision::collider::Collider;
+use crate::physic::solid::Solid;
+use crate::tank::Tank;
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavGrid>()
+            .add_systems(Update, (flag_wall_changes, rebuild_nav_grid).chain());
+    }
+}
+
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+// A grid over the arena in which a cell is blocked when a tank standing on its
+// center would touch a wall.
+#[derive(Resource)]
+pub struct NavGrid {
+    origin: Vec2,
+    cell_size: f32,
+    width: usize,
+    height: usize,
+    blocked: Vec<bool>,
+    dirty: bool,
+}
+
+impl Default for NavGrid {
+    fn default() -> Self {
+        NavGrid {
+            origin: Vec2::ZERO,
+            cell_size: 1.,
+            width: 0,
+            height: 0,
+            blocked: Vec::new(),
+            dirty: true,
+        }
+    }
+}
+
+impl NavGrid {
+    pub fn is_blocked(&self, position: Vec3) -> bool {
+        self.cell(position.truncate())
+            .is_none_or(|cell| self.blocked[cell])
+    }
+
+    // Returns the cell centers leading from `from` to `to`, ending at `to` itself.
+    pub fn find_path(&self, from: Vec3, to: Vec3) -> Option<Vec<Vec3>> {
+        let start = self.nearest_free(self.cell(from.truncate())?)?;
+        let goal = self.nearest_free(self.cell(to.truncate())?)?;
+
+        let mut costs = vec![u32::MAX; self.blocked.len()];
+        let mut came_from = vec![usize::MAX; self.blocked.len()];
+        let mut open = BinaryHeap::new();
+        costs[start] = 0;
+        open.push(Reverse((self.heuristic(start, goal), start)));
+
+        while let Some(Reverse((_, current))) = open.pop() {
+            if current == goal {
+                let mut path = vec![to];
+                let mut cell = came_from[goal];
+                while cell != usize::MAX && cell != start {
+                    path.push(self.center(cell).extend(0.));
+                    cell = came_from[cell];
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for (neighbour, step) in self.neighbours(current) {
+                let cost = costs[current] + step;
+                if cost < costs[neighbour] {
+                    costs[neighbour] = cost;
+                    came_from[neighbour] = current;
+                    open.push(Reverse((cost + self.heuristic(neighbour, goal), neighbour)));
+                }
+            }
+        }
+        None
+    }
+
+    // Skips every waypoint that can be driven past in a straight line, so the
+    // tank heads for the furthest one it can reach directly.
+    pub fn next_waypoint(&self, from: Vec3, to: Vec3) -> Option<Vec3> {
+        let path = self.find_path(from, to)?;
+        path.iter()
+            .rev()
+            .find(|waypoint| self.is_clear(from, **waypoint))
+            .or(path.first())
+            .copied()
+    }
+
+    // Samples the line at half the cell size, which is enough not to step over a cell.
+    fn is_clear(&self, from: Vec3, to: Vec3) -> bool {
+        let samples = (from.distance(to) / (self.cell_size / 2.)).ceil() as usize;
+        (0..=samples).all(|sample| {
+            let position = from.lerp(to, sample as f32 / samples.max(1) as f32);
+            !self.is_blocked(position)
+        })
+    }
+
+    fn cell(&self, position: Vec2) -> Option<usize> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        let local = ((position - self.origin) / self.cell_size).floor();
+        let x = (local.x.max(0.) as usize).min(self.width - 1);
+        let y = (local.y.max(0.) as usize).min(self.height - 1);
+        Some(y * self.width + x)
+    }
+
+    fn center(&self, cell: usize) -> Vec2 {
+        let x = (cell % self.width) as f32;
+        let y = (cell / self.width) as f32;
+        self.origin + (Vec2::new(x, y) + 0.5) * self.cell_size
+    }
+
+    fn heuristic(&self, from: usize, to: usize) -> u32 {
+        let dx = (from % self.width).abs_diff(to % self.width) as u32;
+        let dy = (from / self.width).abs_diff(to / self.width) as u32;
+        STRAIGHT_COST * dx.max(dy) + (DIAGONAL_COST - STRAIGHT_COST) * dx.min(dy)
+    }
+
+    fn free(&self, x: isize, y: isize) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        let cell = y as usize * self.width + x as usize;
+        (!self.blocked[cell]).then_some(cell)
+    }
+
+    // Diagonal steps are only taken when both adjacent cells are free, so paths
+    // never cut the corner of a wall.
+    fn neighbours(&self, cell: usize) -> Vec<(usize, u32)> {
+        let x = (cell % self.width) as isize;
+        let y = (cell / self.width) as isize;
+        let mut neighbours = Vec::with_capacity(8);
+        for (dx, dy) in [
+            (-1, 0),
+            (1, 0),
+            (0, -1),
+            (0, 1),
+            (-1, -1),
+            (-1, 1),
+            (1, -1),
+            (1, 1),
+        ] {
+            let Some(neighbour) = self.free(x + dx, y + dy) else {
+                continue;
+            };
+            if dx == 0 || dy == 0 {
+                neighbours.push((neighbour, STRAIGHT_COST));
+            } else if self.free(x + dx, y).is_some() && self.free(x, y + dy).is_some() {
+                neighbours.push((neighbour, DIAGONAL_COST));
+            }
+        }
+        neighbours
+    }
+
+    // Tanks pressed against a wall stand on blocked cells, so searches start from
+    // the closest free cell instead.
+    fn nearest_free(&self, cell: usize) -> Option<usize> {
+        let mut visited = vec![false; self.blocked.len()];
+        let mut queue = VecDeque::from([cell]);
+        visited[cell] = true;
+        while let Some(current) = queue.pop_front() {
+            if !self.blocked[current] {
+                return Some(current);
+            }
+            let x = (current % self.width) as isize;
+            let y = (current / self.width) as isize;
+            for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let (nx, ny) = (x + dx, y + dy);
+                if nx < 0 || ny < 0 || nx as usize >= self.width || ny as usize >= self.height {
+                    continue;
+                }
+                let neighbour = ny as usize * self.width + nx as usize;
+                if !visited[neighbour] {
+                    visited[neighbour] = true;
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn flag_wall_changes(
+    mut nav_grid: ResMut<NavGrid>,
+    added_wall_query: Query<(), Added<Wall>>,
+    changed_wall_query: Query<(), (With<Wall>, Changed<Transform>)>,
+    mut removed_walls: RemovedComponents<Wall>,
+) {
+    if !added_wall_query.is_empty()
+        || !changed_wall_query.is_empty()
+        || removed_walls.read().next().is_some()
+    {
+        nav_grid.dirty = true;
+    }
+}
+
+fn rebuild_nav_grid(
+    mut nav_grid: ResMut<NavGrid>,
+    solid_query: Query<&Collider, (With<Solid>, Without<Tank>)>,
+    tank_query: Query<&Collider, With<Tank>>,
+    configuration: Res<Configuration>,
+    resolution: Res<Resolution>,
+    time: Res<Time>,
+    mut last_rebuild: Local<Option<f32>>,
+) {
+    if !nav_grid.dirty
+        || last_rebuild.is_some_and(|last_rebuild| {
+            time.elapsed_secs() - last_rebuild < configuration.navigation_rebuild_interval
+        })
+    {
+        return;
+    }
+    // Walls are inflated by the largest tank so that every free cell fits one.
+    // Without any tank yet, the default tank size is used.
+    let clearance = tank_query
+        .iter()
+        .chain([&Tank::collider()])
+        .map(|tank_c| tank_c.width().max(tank_c.height()) / 2.)
+        .fold(0., f32::max);
+
+    let cell_size = configuration.navigation_cell_size;
+    let width = (resolution.screen_dimensions.x / cell_size).ceil() as usize;
+    let height = (resolution.screen_dimensions.y / cell_size).ceil() as usize;
+    let origin = -resolution.screen_dimensions / 2.;
+    let mut blocked = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let center = origin + (Vec2::new(x as f32, y as f32) + 0.5) * cell_size;
+            let probe = Collider::Circle(CircleCollider::new(clearance, center));
+            blocked.push(solid_query.iter().any(|solid_c| probe.intersects(solid_c)));
+        }
+    }
+
+    *nav_grid = NavGrid {
+        origin,
+        cell_size,
+        width,
+        height,
+        blocked,
+        dirty: false,
+    };
+    *last_rebuild = Some(time.elapsed_secs());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CELL_SIZE: f32 = 10.;
+
+    // Rows are listed from the bottom up, `#` marks a blocked cell.
+    fn grid(rows: &[&str]) -> NavGrid {
+        NavGrid {
+            origin: Vec2::ZERO,
+            cell_size: CELL_SIZE,
+            width: rows[0].len(),
+            height: rows.len(),
+            blocked: rows
+                .iter()
+                .flat_map(|row| row.chars().map(|cell| cell == '#'))
+                .collect(),
+            dirty: false,
+        }
+    }
+
+    fn at(x: usize, y: usize) -> Vec3 {
+        Vec3::new(
+            (x as f32 + 0.5) * CELL_SIZE,
+            (y as f32 + 0.5) * CELL_SIZE,
+            0.,
+        )
+    }
+
+    #[test]
+    fn path_ends_at_the_target() {
+        let nav_grid = grid(&["....", "....", "...."]);
+        let path = nav_grid.find_path(at(0, 0), at(3, 2)).unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(path.last(), Some(&at(3, 2)));
+    }
+
+    #[test]
+    fn path_goes_around_walls() {
+        let nav_grid = grid(&["..#..", "..#..", "..#..", "....."]);
+        let path = nav_grid.find_path(at(0, 0), at(4, 0)).unwrap();
+        assert!(path.iter().all(|waypoint| !nav_grid.is_blocked(*waypoint)));
+        assert!(path.contains(&at(2, 3)));
+    }
+
+    #[test]
+    fn path_does_not_cut_corners() {
+        let nav_grid = grid(&[".#", ".."]);
+        let path = nav_grid.find_path(at(0, 0), at(1, 1)).unwrap();
+        assert_eq!(path, vec![at(0, 1), at(1, 1)]);
+    }
+
+    #[test]
+    fn next_waypoint_skips_visible_waypoints() {
+        let open = grid(&["....", "....", "...."]);
+        assert_eq!(open.next_waypoint(at(0, 0), at(3, 2)), Some(at(3, 2)));
+
+        let nav_grid = grid(&["..#..", "..#..", "..#..", "....."]);
+        let waypoint = nav_grid.next_waypoint(at(0, 0), at(4, 0)).unwrap();
+        assert_ne!(waypoint, at(4, 0));
+        assert!(waypoint.distance(at(0, 0)) > CELL_SIZE * 2.);
+    }
+
+    #[test]
+    fn no_path_through_a_closed_wall() {
+        let nav_grid = grid(&["..#..", "..#..", "..#.."]);
+        assert!(nav_grid.find_path(at(0, 0), at(4, 0)).is_none());
+    }
+
+    #[test]
+    fn search_starts_from_the_nearest_free_cell() {
+        let nav_grid = grid(&["##.", "...", "..."]);
+        assert_eq!(nav_grid.nearest_free(0), Some(3));
+        let path = nav_grid.find_path(at(0, 0), at(2, 0)).unwrap();
+        assert_eq!(path, vec![at(1, 1), at(2, 1), at(2, 0)]);
+    }
+
+    #[test]
+    fn no_free_cell() {
+        let nav_grid = grid(&["##", "##"]);
+        assert_eq!(nav_grid.nearest_free(0), None);
+        assert!(nav_grid.is_blocked(at(1, 1)));
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use rand::random_range;
+
+use crate::configuration::resolution::Resolution;
+use crate::configuration::Configuration;
+use crate::environment::chest::Chest;
+use crate::physic::collision::collider::Collider;
+use crate::physic::solid::Solid;
+use crate::tank::Tank;
+
+#[derive(Debug)]
+pub enum PlacementError {
+    AreaTooSmall,
+    NoFreeSpot,
+}
+
+// Finds free spots for anything that gets dropped into the arena: away from
+// solids, the arena edges, tanks and other pickups.
+#[derive(SystemParam)]
+pub struct Placement<'w, 's> {
+    solid_query: Query<'w, 's, (Entity, &'static Collider), With<Solid>>,
+    tank_query: Query<'w, 's, (Entity, &'static Transform), With<Tank>>,
+    chest_query: Query<'w, 's, &'static Transform, With<Chest>>,
+    configuration: Res<'w, Configuration>,
+    resolution: Res<'w, Resolution>,
+}
+
+impl Placement<'_, '_> {
+    pub fn arena(&self) -> Rect {
+        Rect::from_center_size(Vec2::ZERO, self.resolution.screen_dimensions)
+    }
+
+    // Entities in `ignored` are about to be moved and do not block anything, while
+    // `occupied` holds positions that were handed out but not applied yet.
+    pub fn find_position(
+        &self,
+        collider: &Collider,
+        area: Rect,
+        ignored: &[Entity],
+        occupied: &[Vec3],
+    ) -> Result<Vec3, PlacementError> {
+        // Any rotation of the collider has to fit, so its half diagonal is kept clear.
+        let extent = Vec2::new(collider.width(), collider.height()).length() / 2.
+            + self.configuration.placement_margin;
+        let area = area.intersect(self.arena()).inflate(-extent);
+        if area.is_empty() {
+            return Err(PlacementError::AreaTooSmall);
+        }
+
+        for _ in 0..self.configuration.placement_attempts {
+            let position = Vec3::new(
+                random_range(area.min.x..=area.max.x).round(),
+                random_range(area.min.y..=area.max.y).round(),
+                0.,
+            );
+            if self.is_free(collider, position, ignored, occupied) {
+                return Ok(position);
+            }
+        }
+        Err(PlacementError::NoFreeSpot)
+    }
+
+    pub fn is_free(
+        &self,
+        collider: &Collider,
+        position: Vec3,
+        ignored: &[Entity],
+        occupied: &[Vec3],
+    ) -> bool {
+        let mut collider = collider.clone();
+        collider.update(&Transform::from_translation(position));
+        let min_distance = self.configuration.placement_min_distance;
+        !self
+            .solid_query
+            .iter()
+            .any(|(solid, solid_c)| !ignored.contains(&solid) && collider.intersects(solid_c))
+            && self
+                .tank_query
+                .iter()
+                .filter(|(tank, _)| !ignored.contains(tank))
+                .map(|(_, transform)| transform.translation)
+                .chain(
+                    self.chest_query
+                        .iter()
+                        .map(|transform| transform.translation),
+                )
+                .chain(occupied.iter().copied())
+                .all(|other| other.distance(position) >= min_distance)
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+use crate::environment::map::Map;
+use crate::environment::placement::Placement;
+use crate::physic::collision::collider::Collider;
+use crate::projectile::Projectile;
+use crate::tank::Killed;
+use crate::tank::Tank;
+
+pub struct SpawnPlugin;
+
+impl Plugin for SpawnPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_spawn_points)
+            .add_systems(Update, spawn_tanks);
+    }
+}
+
+#[derive(Component)]
+pub struct SpawnPoint {}
+
+fn setup_spawn_points(mut commands: Commands, map: Res<Map>) {
+    for spawn_point in map.spawn_points.iter() {
+        commands.spawn((SpawnPoint {}, *spawn_point));
+    }
+}
+
+// New tanks and killed tanks are both sent to the spawn point furthest away
+// from every enemy tank and live enemy projectile. It runs after the tanks were
+// driven so that nothing moves them away from the spawn point in between.
+pub fn spawn_tanks(
+    mut commands: Commands,
+    mut kills: EventReader<Killed>,
+    mut tank_query: Query<(Entity, &mut Tank, &Transform, &Collider)>,
+    spawn_point_query: Query<&Transform, With<SpawnPoint>>,
+    projectile_query: Query<(&Projectile, &Transform)>,
+    placement: Placement,
+) {
+    let pending: Vec<Entity> = tank_query
+        .iter_mut()
+        .filter(|(_, tank, _, _)| tank.is_added())
+        .map(|(tank, _, _, _)| tank)
+        .chain(kills.read().map(|killed| killed.tank))
+        .collect();
+    let mut occupied: Vec<(Entity, Vec3)> = tank_query
+        .iter()
+        .filter(|(tank, _, _, _)| !pending.contains(tank))
+        .map(|(tank, _, transform, _)| (tank, transform.translation))
+        .collect();
+
+    let mut placed: Vec<Vec3> = Vec::new();
+    for tank in pending.iter().copied() {
+        let Ok((_, mut tank_state, transform, collider)) = tank_query.get_mut(tank) else {
+            continue;
+        };
+        let threat_distance = |position: Vec3| {
+            occupied
+                .iter()
+                .filter(|(other, _)| *other != tank)
+                .map(|(_, other_position)| other_position.distance(position))
+                .chain(
+                    projectile_query
+                        .iter()
+                        .filter(|(projectile, _)| projectile.owner != tank)
+                        .map(|(_, projectile)| projectile.translation.distance(position)),
+                )
+                .fold(f32::MAX, f32::min)
+        };
+        let mut spawn_points: Vec<&Transform> = spawn_point_query.iter().collect();
+        spawn_points.sort_by(|first, second| {
+            threat_distance(second.translation).total_cmp(&threat_distance(first.translation))
+        });
+        // A spawn point can be blocked, e.g. by a moving wall, so the safest free one
+        // wins and a random free spot is the last resort.
+        let safest = spawn_points.into_iter().find(|spawn_point| {
+            placement.is_free(collider, spawn_point.translation, &pending, &placed)
+        });
+
+        let spawn_location = match safest {
+            Some(spawn_point) => spawn_point.with_scale(transform.scale),
+            None => match placement.find_position(collider, placement.arena(), &pending, &placed) {
+                Ok(position) => transform.with_translation(position),
+                Err(error) => {
+                    warn!("Could not place a tank: {error:?}");
+                    *transform
+                }
+            },
+        };
+        // A collision right after spawning must not send the tank back to where it died.
+        tank_state.last_position = spawn_location.translation;
+        occupied.push((tank, spawn_location.translation));
+        placed.push(spawn_location.translation);
+        commands.entity(tank).insert(spawn_location);
+    }
+}
@@ -1,21 +1,60 @@
+use bevy::color::palettes::css::*;
 use bevy::prelude::*;
 
-use crate::physic::collision::Collision;
+use crate::environment::map::Map;
+use crate::environment::map::WallPath;
 use crate::physic::collision::collider::Collider;
 use crate::physic::collision::polygon::PolygonCollider;
+use crate::physic::collision::Collision;
 use crate::physic::solid::Solid;
+use crate::physic::solid::SolidHit;
+use crate::physic::solid::SurfaceVelocity;
+use crate::tank::Tank;
 
 pub struct WallPlugin;
 
 impl Plugin for WallPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, setup_walls);
+        app.add_systems(Startup, setup_walls)
+            .add_systems(FixedUpdate, (move_kinematic_walls, carry_tanks).chain())
+            .add_systems(Update, (damage_walls, render_damaged_walls).chain());
     }
 }
 
 #[derive(Component)]
 pub struct Wall {}
 
+#[derive(Component)]
+pub struct Durability {
+    pub hit_points: f32,
+    pub max_hit_points: f32,
+}
+
+impl Durability {
+    pub fn new(hit_points: f32) -> Durability {
+        Durability {
+            hit_points,
+            max_hit_points: hit_points,
+        }
+    }
+
+    pub fn stage(&self) -> usize {
+        let ratio = self.hit_points / self.max_hit_points;
+        let stage = ((1. - ratio) * DAMAGE_STAGES.len() as f32) as usize;
+        stage.min(DAMAGE_STAGES.len() - 1)
+    }
+}
+
+const DAMAGE_STAGES: [Srgba; 3] = [LIGHT_GRAY, ORANGE, RED];
+
+#[derive(Component)]
+pub struct KinematicWall {
+    pub path: WallPath,
+    pub origin: Transform,
+    pub elapsed: f32,
+    pub previous: Transform,
+}
+
 #[derive(Bundle)]
 pub struct WallBundle {
     collider: Collision,
@@ -23,6 +62,13 @@ pub struct WallBundle {
     wall: Wall,
 }
 
+#[derive(Bundle)]
+pub struct KinematicWallBundle {
+    kinematic_wall: KinematicWall,
+    surface_velocity: SurfaceVelocity,
+    wall: WallBundle,
+}
+
 impl WallBundle {
     pub fn new(vertices: Box<[Vec2]>, transform: Transform) -> WallBundle {
         WallBundle {
@@ -33,15 +79,137 @@ impl WallBundle {
     }
 }
 
-fn setup_walls(mut commands: Commands) {
-    let vertices = Box::new([
-        Vec2::new(0., 20.),
-        Vec2::new(20., 120.),
-        Vec2::new(120., 100.),
-        Vec2::new(100., 0.),
-    ]);
-    commands.spawn(WallBundle::new(
-        vertices,
-        Transform::from_xyz(100., 100., 0.),
-    ));
+impl KinematicWallBundle {
+    pub fn new(vertices: Box<[Vec2]>, transform: Transform, path: WallPath) -> KinematicWallBundle {
+        KinematicWallBundle {
+            kinematic_wall: KinematicWall {
+                path,
+                origin: transform,
+                elapsed: 0.,
+                previous: transform,
+            },
+            surface_velocity: SurfaceVelocity::default(),
+            wall: WallBundle::new(vertices, transform),
+        }
+    }
+}
+
+fn setup_walls(mut commands: Commands, map: Res<Map>) {
+    for wall in map.walls.iter() {
+        let mut entity = match &wall.path {
+            Some(path) => commands.spawn(KinematicWallBundle::new(
+                wall.vertices.clone(),
+                wall.transform,
+                path.clone(),
+            )),
+            None => commands.spawn(WallBundle::new(wall.vertices.clone(), wall.transform)),
+        };
+        if let Some(hit_points) = wall.durability {
+            entity.insert(Durability::new(hit_points));
+        }
+    }
+}
+
+fn move_kinematic_walls(
+    mut wall_query: Query<(
+        &mut KinematicWall,
+        &mut SurfaceVelocity,
+        &mut Transform,
+        &mut Collider,
+    )>,
+    time: Res<Time>,
+) {
+    for (mut kinematic_wall, mut surface_velocity, mut transform, mut collider) in
+        wall_query.iter_mut()
+    {
+        kinematic_wall.elapsed += time.delta_secs();
+        kinematic_wall.previous = *transform;
+        *transform = kinematic_wall
+            .path
+            .transform_at(&kinematic_wall.origin, kinematic_wall.elapsed);
+        let previous = kinematic_wall.previous;
+        let turned = (transform.rotation * previous.rotation.inverse())
+            .to_euler(EulerRot::YXZ)
+            .2;
+        *surface_velocity = SurfaceVelocity {
+            linear: (transform.translation - previous.translation) / time.delta_secs(),
+            angular: turned / time.delta_secs(),
+            origin: transform.translation,
+        };
+        collider.update(&transform);
+    }
+}
+
+// Tanks touching a moving wall are carried along as if attached to it, unless
+// that would push them into another solid. Their last position moves with them
+// so the collision check afterwards doesn't send them back into the wall.
+fn carry_tanks(
+    wall_query: Query<(Entity, &KinematicWall, &Transform, &Collider), Without<Tank>>,
+    mut tank_query: Query<(Entity, &mut Tank, &mut Transform, &Collider)>,
+    solid_query: Query<(Entity, &Collider), With<Solid>>,
+) {
+    for (tank, mut tank_state, mut tank_transform, tank_c) in tank_query.iter_mut() {
+        for (wall, kinematic_wall, transform, wall_c) in wall_query.iter() {
+            if !tank_c.intersects(wall_c) {
+                continue;
+            }
+            let previous = kinematic_wall.previous;
+            let local =
+                previous.rotation.inverse() * (tank_transform.translation - previous.translation);
+            let carried = transform.translation + transform.rotation * local;
+
+            let mut carried_c = tank_c.clone();
+            carried_c.update(&tank_transform.with_translation(carried));
+            let blocked = solid_query.iter().any(|(solid, solid_c)| {
+                solid != wall
+                    && solid != tank
+                    && !tank_c.intersects(solid_c)
+                    && carried_c.intersects(solid_c)
+            });
+            if blocked {
+                continue;
+            }
+
+            tank_state.last_position += carried - tank_transform.translation;
+            tank_transform.translation = carried;
+            break;
+        }
+    }
+}
+
+fn damage_walls(
+    mut commands: Commands,
+    mut solid_hits: EventReader<SolidHit>,
+    mut wall_query: Query<&mut Durability, With<Wall>>,
+) {
+    for hit in solid_hits.read() {
+        let Ok(mut durability) = wall_query.get_mut(hit.solid) else {
+            continue;
+        };
+        if durability.hit_points <= 0. {
+            continue;
+        }
+        durability.hit_points -= hit.damage;
+        if durability.hit_points <= 0. {
+            commands.entity(hit.solid).despawn();
+        }
+    }
+}
+
+fn render_damaged_walls(
+    mut gizmos: Gizmos,
+    wall_query: Query<(&Collider, &Transform, &Durability), With<Wall>>,
+) {
+    for (collider, transform, durability) in wall_query.iter() {
+        let Collider::Polygon(polygon) = collider else {
+            continue;
+        };
+        let translation = transform.translation.xy();
+        let rotation = transform.rotation.to_euler(EulerRot::YXZ).2;
+        let isometry = Isometry2d::new(translation, Rot2::radians(rotation));
+        let polygon = BoxedPolygon {
+            vertices: polygon.relative_vertices.clone(),
+        };
+        gizmos.primitive_2d(&polygon, isometry, DAMAGE_STAGES[durability.stage()]);
+    }
 }
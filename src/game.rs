@@ -8,6 +8,7 @@ use crate::physic;
 use crate::player;
 use crate::projectile;
 use crate::tank;
+use crate::weapon;
 
 pub struct GamePlugin;
 
@@ -22,6 +23,7 @@ impl Plugin for GamePlugin {
             projectile::ProjectilePlugin,
             tank::TankPlugin,
             environment::EnvironmentPlugin,
+            weapon::WeaponPlugin,
         ))
         .add_systems(Startup, setup_scene);
     }
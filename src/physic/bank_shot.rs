@@ -0,0 +1,191 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::physic::collision::collider::Collider;
+use crate::physic::collision::ray::Raycast;
+
+pub struct BankShot {
+    pub rotation: Quat,
+    pub path: Vec<Vec2>,
+    pub bounces: u32,
+}
+
+impl BankShot {
+    pub fn length(&self) -> f32 {
+        self.path
+            .windows(2)
+            .map(|segment| segment[0].distance(segment[1]))
+            .sum()
+    }
+}
+
+// Searches firing directions for a projectile that reaches a target after
+// bouncing off the given solids. It only needs colliders, so it works for AI
+// aiming as well as for checking maps outside of a running game.
+pub struct BankShotSolver<'a> {
+    pub solids: &'a [&'a Collider],
+    pub samples: u32,
+    pub max_distance: f32,
+}
+
+impl BankShotSolver<'_> {
+    // Prefers the shot with the fewest bounces and then the shortest path.
+    pub fn solve(
+        &self,
+        shooter: &Transform,
+        muzzle: Vec3,
+        target: Vec2,
+        target_radius: f32,
+        projectile_radius: f32,
+        bounce_budget: u32,
+    ) -> Option<BankShot> {
+        (0..self.samples)
+            .filter_map(|sample| {
+                let rotation = Quat::from_rotation_z(TAU * sample as f32 / self.samples as f32);
+                let origin = (shooter.translation + rotation * muzzle).truncate();
+                let direction = (rotation * Vec3::Y).truncate();
+                self.trace(
+                    origin,
+                    direction,
+                    target,
+                    target_radius + projectile_radius,
+                    projectile_radius,
+                    bounce_budget,
+                )
+                .map(|(path, bounces)| BankShot {
+                    rotation,
+                    path,
+                    bounces,
+                })
+            })
+            .min_by(|first, second| {
+                first
+                    .bounces
+                    .cmp(&second.bounces)
+                    .then(first.length().total_cmp(&second.length()))
+            })
+    }
+
+    fn trace(
+        &self,
+        mut origin: Vec2,
+        mut direction: Vec2,
+        target: Vec2,
+        reach: f32,
+        projectile_radius: f32,
+        bounce_budget: u32,
+    ) -> Option<(Vec<Vec2>, u32)> {
+        let mut remaining = self.max_distance;
+        let mut path = vec![origin];
+
+        for bounces in 0..=bounce_budget {
+            let hit = self
+                .solids
+                .iter()
+                .filter_map(|solid| solid.circle_cast(origin, direction, projectile_radius))
+                .filter(|hit| hit.distance <= remaining)
+                .min_by(|a, b| a.distance.total_cmp(&b.distance));
+
+            let travelled = hit.map_or(remaining, |hit| hit.distance);
+            let end = origin + direction * travelled;
+            if distance_to_segment(target, origin, end) <= reach {
+                path.push(target);
+                return Some((path, bounces));
+            }
+
+            let hit = hit?;
+            path.push(end);
+            remaining -= travelled;
+            direction = direction.reflect(hit.normal);
+            origin = end + hit.normal * 0.01;
+        }
+        None
+    }
+}
+
+fn distance_to_segment(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    if length_squared <= f32::EPSILON {
+        return point.distance(start);
+    }
+    let along = ((point - start).dot(segment) / length_squared).clamp(0., 1.);
+    point.distance(start + segment * along)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physic::collision::polygon::PolygonCollider;
+
+    fn wall(min: Vec2, max: Vec2) -> Collider {
+        Collider::Polygon(PolygonCollider::new(Box::new([
+            Vec2::new(min.x, min.y),
+            Vec2::new(min.x, max.y),
+            Vec2::new(max.x, max.y),
+            Vec2::new(max.x, min.y),
+        ])))
+    }
+
+    fn solve(
+        walls: &[Collider],
+        target: Vec2,
+        projectile_radius: f32,
+        bounces: u32,
+    ) -> Option<BankShot> {
+        let solids: Vec<&Collider> = walls.iter().collect();
+        let solver = BankShotSolver {
+            solids: &solids,
+            samples: 360,
+            max_distance: 1000.,
+        };
+        solver.solve(
+            &Transform::IDENTITY,
+            Vec3::ZERO,
+            target,
+            10.,
+            projectile_radius,
+            bounces,
+        )
+    }
+
+    #[test]
+    fn straight_shot() {
+        let shot = solve(&[], Vec2::new(0., 100.), 2., 2).unwrap();
+        assert_eq!(shot.bounces, 0);
+        assert_eq!(shot.path, vec![Vec2::ZERO, Vec2::new(0., 100.)]);
+    }
+
+    #[test]
+    fn single_bounce_around_a_wall() {
+        let walls = [
+            wall(Vec2::new(90., -50.), Vec2::new(110., 50.)),
+            wall(Vec2::new(-500., 100.), Vec2::new(500., 120.)),
+        ];
+        let shot = solve(&walls, Vec2::new(200., 0.), 2., 1).unwrap();
+        assert_eq!(shot.bounces, 1);
+        assert_eq!(shot.path.len(), 3);
+        // The bounce happens a radius below the reflecting wall.
+        assert!((shot.path[1].y - 98.).abs() < 0.01);
+    }
+
+    #[test]
+    fn blocked_shot() {
+        let walls = [
+            wall(Vec2::new(90., -50.), Vec2::new(110., 50.)),
+            wall(Vec2::new(-500., 100.), Vec2::new(500., 120.)),
+        ];
+        assert!(solve(&walls, Vec2::new(200., 0.), 2., 0).is_none());
+    }
+
+    #[test]
+    fn gap_narrower_than_the_projectile() {
+        let walls = [
+            wall(Vec2::new(90., 2.), Vec2::new(110., 200.)),
+            wall(Vec2::new(90., -200.), Vec2::new(110., -2.)),
+        ];
+        assert!(solve(&walls, Vec2::new(200., 0.), 1., 0).is_some());
+        assert!(solve(&walls, Vec2::new(200., 0.), 3., 0).is_none());
+    }
+}
@@ -1,8 +1,13 @@
 use bevy::prelude::*;
 
+use crate::configuration::Configuration;
 use crate::physic::collision::collider::Collider;
 use crate::physic::solid::Solid;
+use crate::physic::solid::SolidHit;
+use crate::physic::solid::SurfaceVelocity;
+use crate::physic::velocity::AngularVelocity;
 use crate::physic::velocity::Velocity;
+use crate::projectile::Projectile;
 
 pub struct BouncePlugin;
 
@@ -20,19 +25,49 @@ pub struct Bounce {
 
 fn apply_bounce(
     mut commands: Commands,
-    mut bounce_query: Query<(Entity, &mut Velocity, &mut Bounce, &Collider)>,
-    solid_query: Query<(Entity, &Collider), With<Solid>>,
+    mut bounce_query: Query<(
+        Entity,
+        &mut Velocity,
+        &mut Bounce,
+        &Collider,
+        Option<&mut AngularVelocity>,
+    )>,
+    projectile_query: Query<&Projectile>,
+    solid_query: Query<(Entity, &Collider, Option<&SurfaceVelocity>), With<Solid>>,
+    configuration: Res<Configuration>,
+    mut solid_hits: EventWriter<SolidHit>,
 ) {
-    for (entity, mut velocity, mut bounce, bounce_collider) in bounce_query.iter_mut() {
-        for (wall_entity, wall_collider) in solid_query.iter() {
+    for (entity, mut velocity, mut bounce, bounce_collider, mut angular_velocity) in
+        bounce_query.iter_mut()
+    {
+        for (wall_entity, wall_collider, surface_velocity) in solid_query.iter() {
             if bounce_collider.intersects(wall_collider)
                 && bounce.last_bounce.index() != wall_entity.index()
             {
+                solid_hits.write(SolidHit {
+                    solid: wall_entity,
+                    damage: projectile_query
+                        .get(entity)
+                        .map_or(0., |projectile| projectile.damage),
+                });
                 if bounce.bounce_count > 0 {
                     bounce.bounce_count -= 1;
                     bounce.last_bounce = wall_entity;
-                    let surface = bounce_collider.get_contact_vector(wall_collider);
-                    **velocity = -velocity.reflect(surface.normalize());
+                    let surface = bounce_collider
+                        .get_contact_vector(wall_collider)
+                        .normalize();
+                    if let Some(angular_velocity) = angular_velocity.as_mut() {
+                        // The velocity along the surface is what grips the wall
+                        // and turns into spin.
+                        ***angular_velocity +=
+                            configuration.bounce_spin_transfer * velocity.dot(surface);
+                    }
+                    // Reflect relative to the wall so moving walls bat projectiles along.
+                    let surface_velocity = surface_velocity.map_or(Vec3::ZERO, |surface| {
+                        surface.at(bounce_collider.center().extend(0.))
+                    });
+                    **velocity =
+                        -(**velocity - surface_velocity).reflect(surface) + surface_velocity;
                 } else {
                     commands.entity(entity).despawn();
                 }
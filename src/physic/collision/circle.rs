@@ -22,6 +22,10 @@ impl CircleCollider {
         }
     }
 
+    pub fn distance_to(&self, point: Vec2) -> f32 {
+        (self.center.distance(point) - self.radius).max(0.)
+    }
+
     pub fn update_center(&mut self, transform: &Transform) {
         self.center = transform.translation.xy();
     }
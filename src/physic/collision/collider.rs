@@ -48,6 +48,21 @@ impl Collider {
         }
     }
 
+    // Distance from the point to the collider's edge, zero when it is inside.
+    pub fn distance_to(&self, point: Vec2) -> f32 {
+        match self {
+            Collider::Polygon(polygon) => polygon.distance_to(point),
+            Collider::Circle(circle) => circle.distance_to(point),
+        }
+    }
+
+    pub fn center(&self) -> Vec2 {
+        match self {
+            Collider::Polygon(polygon) => polygon.center(),
+            Collider::Circle(circle) => circle.center,
+        }
+    }
+
     pub fn width(&self) -> f32 {
         match self {
             Collider::Polygon(polygon) => polygon.width,
@@ -1,17 +1,21 @@
 use bevy::prelude::*;
 
+use crate::configuration::Configuration;
 use crate::physic::collision::collider::update_colliders;
 use crate::physic::collision::collider::Collider;
 use crate::physic::collision::intersection::Intersection;
+use crate::physic::impulse::Impulse;
 use crate::physic::solid::Solid;
-use crate::player::Player;
+use crate::projectile::mine::Mine;
 use crate::projectile::Projectile;
+use crate::tank::Damage;
 use crate::tank::Tank;
 
 pub mod circle;
 pub mod collider;
 mod intersection;
 pub mod polygon;
+pub mod ray;
 mod visibility;
 mod volume;
 
@@ -19,10 +23,8 @@ pub struct CollisionPlugin;
 
 impl Plugin for CollisionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(visibility::VisibilityPlugin).add_systems(
-            PostUpdate,
-            (update_colliders, check_player_collision).chain(),
-        );
+        app.add_plugins(visibility::VisibilityPlugin)
+            .add_systems(PostUpdate, (update_colliders, check_tank_collision).chain());
     }
 }
 
@@ -44,22 +46,41 @@ impl Collision {
     }
 }
 
-fn check_player_collision(
-    mut player_query: Query<(Entity, &mut Transform, &Player, &Collider, &Tank)>,
+fn check_tank_collision(
+    mut commands: Commands,
+    mut tank_query: Query<(Entity, &mut Transform, &Tank, &Collider, &mut Impulse)>,
     solid_query: Query<(Entity, &Collider), With<Solid>>,
-    projectile_query: Query<&Collider, With<Projectile>>,
+    tanks: Query<(), With<Tank>>,
+    projectile_query: Query<(Entity, &Projectile, &Collider, Has<Mine>)>,
+    configuration: Res<Configuration>,
+    mut damages: EventWriter<Damage>,
 ) {
-    for (player_entity, mut transform, player, player_c, tank) in player_query.iter_mut() {
+    for (tank_entity, mut transform, tank, tank_c, mut impulse) in tank_query.iter_mut() {
         for (solid_entity, solid_c) in solid_query.iter() {
-            if player_entity.index() != solid_entity.index() && player_c.intersects(solid_c) {
-                player.set_to_last_pos(&mut transform);
-                return;
+            if tank_entity.index() != solid_entity.index() && tank_c.intersects(solid_c) {
+                tank.set_to_last_position(&mut transform);
+                if tanks.contains(solid_entity) {
+                    impulse.push_from(
+                        solid_c.center().extend(0.),
+                        tank_c.center().extend(0.),
+                        configuration.tank_collision_knockback,
+                    );
+                }
+                break;
             }
         }
 
-        for projectile_c in projectile_query.iter() {
-            if player_c.intersects(projectile_c) {
-                *transform = tank.spawn_location.clone();
+        // Projectiles hit a tank even while it is pushed against a wall.
+        for (entity, projectile, projectile_c, is_mine) in projectile_query.iter() {
+            // Mines are set off by their proximity trigger instead of on contact.
+            if !is_mine && tank_c.intersects(projectile_c) {
+                commands.entity(entity).despawn();
+                damages.write(Damage {
+                    tank: tank_entity,
+                    amount: projectile.damage,
+                    origin: projectile_c.center().extend(0.),
+                    knockback: configuration.projectile_knockback,
+                });
             }
         }
     }
@@ -31,6 +31,35 @@ impl PolygonCollider {
         }
     }
 
+    pub fn center(&self) -> Vec2 {
+        let sum: Vec2 = self.vertices.iter().sum();
+        sum / self.vertices.len() as f32
+    }
+
+    // Zero for points inside the polygon.
+    pub fn distance_to(&self, point: Vec2) -> f32 {
+        let mut inside = false;
+        let mut min = f32::MAX;
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            let next_vertex = &self.vertices[(index + 1) % self.vertices.len()];
+
+            let edge = next_vertex - vertex;
+            let along = ((point - vertex).dot(edge) / edge.length_squared()).clamp(0., 1.);
+            min = min.min(point.distance(vertex + edge * along));
+
+            if (vertex.y > point.y) != (next_vertex.y > point.y)
+                && point.x < vertex.x + (point.y - vertex.y) / (next_vertex.y - vertex.y) * edge.x
+            {
+                inside = !inside;
+            }
+        }
+        if inside {
+            0.
+        } else {
+            min
+        }
+    }
+
     pub fn project_vertices(&self, axis: Vec2) -> (f32, f32) {
         let mut min = f32::MAX;
         let mut max = f32::MIN;
@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+
+use crate::physic::collision::circle::CircleCollider;
+use crate::physic::collision::collider::Collider;
+use crate::physic::collision::polygon::PolygonCollider;
+
+#[derive(Clone, Copy)]
+pub struct RayHit {
+    pub distance: f32,
+    pub point: Vec2,
+    pub normal: Vec2,
+}
+
+pub trait Raycast {
+    fn raycast(&self, origin: Vec2, direction: Vec2) -> Option<RayHit>;
+
+    // Casts a circle instead of a point. The hit point is where the circle's
+    // center is when its edge first touches the collider.
+    fn circle_cast(&self, origin: Vec2, direction: Vec2, radius: f32) -> Option<RayHit>;
+}
+
+impl Raycast for PolygonCollider {
+    fn raycast(&self, origin: Vec2, direction: Vec2) -> Option<RayHit> {
+        let mut closest: Option<RayHit> = None;
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            let next_vertex = &self.vertices[(index + 1) % self.vertices.len()];
+
+            let edge = next_vertex - vertex;
+            let denominator = direction.perp_dot(edge);
+            if denominator.abs() < f32::EPSILON {
+                continue;
+            }
+
+            let offset = vertex - origin;
+            let distance = offset.perp_dot(edge) / denominator;
+            let along_edge = offset.perp_dot(direction) / denominator;
+            if distance <= 0. || !(0. ..=1.).contains(&along_edge) {
+                continue;
+            }
+
+            if closest.is_some_and(|hit| hit.distance <= distance) {
+                continue;
+            }
+
+            let mut normal = Vec2::new(-edge.y, edge.x).normalize();
+            if normal.dot(direction) > 0. {
+                normal = -normal;
+            }
+            closest = Some(RayHit {
+                distance,
+                point: origin + direction * distance,
+                normal,
+            });
+        }
+        closest
+    }
+
+    // The polygon is grown by the radius: its edges move outward and its
+    // corners become circles.
+    fn circle_cast(&self, origin: Vec2, direction: Vec2, radius: f32) -> Option<RayHit> {
+        let center = self.center();
+        let mut closest: Option<RayHit> = None;
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            let next_vertex = &self.vertices[(index + 1) % self.vertices.len()];
+
+            let edge = next_vertex - vertex;
+            let mut normal = Vec2::new(-edge.y, edge.x).normalize_or_zero();
+            if normal.dot((vertex + next_vertex) / 2. - center) < 0. {
+                normal = -normal;
+            }
+
+            let mut hits = Vec::with_capacity(2);
+            if normal.dot(direction) < 0. {
+                let offset = vertex + normal * radius - origin;
+                let denominator = direction.perp_dot(edge);
+                let distance = offset.perp_dot(edge) / denominator;
+                let along_edge = offset.perp_dot(direction) / denominator;
+                if distance > 0. && (0. ..=1.).contains(&along_edge) {
+                    hits.push((distance, normal));
+                }
+            }
+            if let Some(distance) = entry_distance(origin, direction, *vertex, radius) {
+                let point = origin + direction * distance;
+                hits.push((distance, (point - vertex).normalize_or_zero()));
+            }
+
+            for (distance, normal) in hits {
+                if closest.is_none_or(|hit| distance < hit.distance) {
+                    closest = Some(RayHit {
+                        distance,
+                        point: origin + direction * distance,
+                        normal,
+                    });
+                }
+            }
+        }
+        closest
+    }
+}
+
+impl Raycast for CircleCollider {
+    fn raycast(&self, origin: Vec2, direction: Vec2) -> Option<RayHit> {
+        let offset = origin - self.center;
+        let b = offset.dot(direction);
+        let c = offset.length_squared() - self.radius * self.radius;
+        let discriminant = b * b - c;
+        if discriminant < 0. {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let distance = if -b - root > 0. { -b - root } else { -b + root };
+        if distance <= 0. {
+            return None;
+        }
+
+        let point = origin + direction * distance;
+        Some(RayHit {
+            distance,
+            point,
+            normal: (point - self.center).normalize(),
+        })
+    }
+
+    fn circle_cast(&self, origin: Vec2, direction: Vec2, radius: f32) -> Option<RayHit> {
+        CircleCollider::new(self.radius + radius, self.center).raycast(origin, direction)
+    }
+}
+
+impl Raycast for Collider {
+    fn raycast(&self, origin: Vec2, direction: Vec2) -> Option<RayHit> {
+        match self {
+            Collider::Polygon(polygon) => polygon.raycast(origin, direction),
+            Collider::Circle(circle) => circle.raycast(origin, direction),
+        }
+    }
+
+    fn circle_cast(&self, origin: Vec2, direction: Vec2, radius: f32) -> Option<RayHit> {
+        match self {
+            Collider::Polygon(polygon) => polygon.circle_cast(origin, direction, radius),
+            Collider::Circle(circle) => circle.circle_cast(origin, direction, radius),
+        }
+    }
+}
+
+// Distance along the ray at which it enters the circle, if it does.
+fn entry_distance(origin: Vec2, direction: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let offset = origin - center;
+    let b = offset.dot(direction);
+    let c = offset.length_squared() - radius * radius;
+    let discriminant = b * b - c;
+    if discriminant < 0. {
+        return None;
+    }
+    let distance = -b - discriminant.sqrt();
+    (distance > 0.).then_some(distance)
+}
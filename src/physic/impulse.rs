@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use crate::configuration::Configuration;
+use crate::physic::collision::collider::Collider;
+use crate::physic::solid::Solid;
+
+pub struct ImpulsePlugin;
+
+impl Plugin for ImpulsePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, apply_impulse);
+    }
+}
+
+#[derive(Component, Deref, DerefMut, Default)]
+pub struct Impulse(pub Vec3);
+
+impl Impulse {
+    pub fn push(&mut self, force: Vec3) {
+        **self += force;
+    }
+
+    pub fn push_from(&mut self, origin: Vec3, position: Vec3, strength: f32) {
+        let direction = (position - origin).with_z(0.).normalize_or_zero();
+        self.push(direction * strength);
+    }
+}
+
+fn apply_impulse(
+    mut impulse_query: Query<(Entity, &mut Transform, &mut Impulse, &Collider)>,
+    solid_query: Query<(Entity, &Collider), With<Solid>>,
+    configuration: Res<Configuration>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut impulse, collider) in impulse_query.iter_mut() {
+        if impulse.length() < configuration.impulse_threshold {
+            **impulse = Vec3::ZERO;
+            continue;
+        }
+
+        let mut moved = *transform;
+        moved.translation += **impulse * time.delta_secs();
+        let mut moved_collider = collider.clone();
+        moved_collider.update(&moved);
+
+        // Solids the entity already overlaps are ignored so it can be pushed
+        // out of them instead of getting stuck.
+        let blocked = solid_query.iter().any(|(solid_entity, solid_c)| {
+            entity.index() != solid_entity.index()
+                && !collider.intersects(solid_c)
+                && moved_collider.intersects(solid_c)
+        });
+
+        if blocked {
+            **impulse = Vec3::ZERO;
+            continue;
+        }
+
+        transform.translation = moved.translation;
+        **impulse *= (-configuration.impulse_decay * time.delta_secs()).exp();
+    }
+}
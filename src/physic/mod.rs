@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 
+pub mod bank_shot;
 pub mod bounce;
 pub mod collision;
+pub mod impulse;
 pub mod solid;
 pub mod velocity;
 
@@ -12,7 +14,9 @@ impl Plugin for PhysicPlugin {
         app.add_plugins((
             bounce::BouncePlugin,
             collision::CollisionPlugin,
+            impulse::ImpulsePlugin,
             velocity::VelocityPlugin,
-        ));
+        ))
+        .add_event::<solid::SolidHit>();
     }
 }
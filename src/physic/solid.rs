@@ -2,3 +2,41 @@ use bevy::prelude::*;
 
 #[derive(Component)]
 pub struct Solid {}
+
+// How fast the surface of a moving solid travels, so bounces can take it along.
+// The solid moves with `linear` and turns around `origin` with `angular`.
+#[derive(Component, Default)]
+pub struct SurfaceVelocity {
+    pub linear: Vec3,
+    pub angular: f32,
+    pub origin: Vec3,
+}
+
+impl SurfaceVelocity {
+    pub fn at(&self, point: Vec3) -> Vec3 {
+        self.linear + (point - self.origin).truncate().perp().extend(0.) * self.angular
+    }
+}
+
+#[derive(Event)]
+pub struct SolidHit {
+    pub solid: Entity,
+    pub damage: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning_surface_moves_around_its_origin() {
+        let surface = SurfaceVelocity {
+            linear: Vec3::new(5., 0., 0.),
+            angular: 2.,
+            origin: Vec3::new(10., 0., 0.),
+        };
+        assert_eq!(surface.at(Vec3::new(10., 0., 0.)), Vec3::new(5., 0., 0.));
+        assert_eq!(surface.at(Vec3::new(20., 0., 0.)), Vec3::new(5., 20., 0.));
+        assert_eq!(surface.at(Vec3::new(10., 10., 0.)), Vec3::new(-15., 0., 0.));
+    }
+}
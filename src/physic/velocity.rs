@@ -4,16 +4,28 @@ pub struct VelocityPlugin;
 
 impl Plugin for VelocityPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(FixedUpdate, apply_velocity);
+        app.add_systems(FixedUpdate, (apply_velocity, apply_angular_velocity));
     }
 }
 
 #[derive(Component, Deref, DerefMut)]
 pub struct Velocity(pub Vec3);
 
+#[derive(Component, Deref, DerefMut)]
+pub struct AngularVelocity(pub f32);
+
 fn apply_velocity(mut velocity_query: Query<(&mut Transform, &Velocity)>, time: Res<Time>) {
     for (mut transform, velocity) in velocity_query.iter_mut() {
         transform.translation.x += velocity.x * time.delta_secs();
         transform.translation.y += velocity.y * time.delta_secs();
     }
 }
+
+fn apply_angular_velocity(
+    mut angular_velocity_query: Query<(&mut Transform, &AngularVelocity)>,
+    time: Res<Time>,
+) {
+    for (mut transform, angular_velocity) in angular_velocity_query.iter_mut() {
+        transform.rotate_z(**angular_velocity * time.delta_secs());
+    }
+}
@@ -6,15 +6,29 @@ use crate::configuration::controls::Controls;
 use crate::configuration::controls::Movement;
 use crate::configuration::resolution::Resolution;
 use crate::configuration::Configuration;
+use crate::environment::spawn::spawn_tanks;
+use crate::tank::Tank;
 use crate::tank::TankBundle;
+use crate::weapon::definition::Weapons;
+use crate::weapon::inventory::Inventory;
+use crate::weapon::Magazine;
+use crate::weapon::Shot;
 use crate::weapon::Weapon;
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, setup_player)
-            .add_systems(Update, (move_player, shoot).chain());
+        app.add_systems(Startup, setup_player).add_systems(
+            Update,
+            (
+                move_player,
+                switch_weapon,
+                shoot.run_if(resource_exists::<Weapons>),
+            )
+                .chain()
+                .before(spawn_tanks),
+        );
     }
 }
 
@@ -22,7 +36,6 @@ impl Plugin for PlayerPlugin {
 pub struct Player {
     controls: Movement,
     last_shot: SystemTime,
-    last_pos: Vec3,
 }
 
 impl Player {
@@ -30,13 +43,8 @@ impl Player {
         Player {
             controls,
             last_shot: SystemTime::now(),
-            last_pos: Vec3::ZERO,
         }
     }
-
-    pub fn set_to_last_pos(&self, transform: &mut Transform) {
-        transform.translation = self.last_pos;
-    }
 }
 
 fn setup_player(
@@ -44,31 +52,38 @@ fn setup_player(
     assets_server: Res<AssetServer>,
     resolution: Res<Resolution>,
     controls: Res<Controls>,
+    configuration: Res<Configuration>,
 ) {
     let player_1_texture = assets_server.load("player/tank_yellow.png");
     let player_2_texture = assets_server.load("player/tank_pink.png");
-    let spawn_location_1 = Transform::from_translation(Vec3::new(0., 0., 0.))
-        .with_scale(Vec3::splat(resolution.tank_pixel_ratio));
-    let spawn_location_2 = Transform::from_translation(Vec3::new(-100., 0., 0.))
-        .with_scale(Vec3::splat(resolution.tank_pixel_ratio));
+    // Only the scale matters, `spawn_tanks` moves new tanks to a spawn point.
+    let spawn_location = Transform::from_scale(Vec3::splat(resolution.tank_pixel_ratio));
     commands.spawn((
         Player::new(controls.movement.clone()),
-        TankBundle::new(spawn_location_1, Sprite::from_image(player_1_texture)),
+        TankBundle::new(
+            spawn_location,
+            Sprite::from_image(player_1_texture),
+            &configuration,
+        ),
     ));
 
     commands.spawn((
         Player::new(controls.second_movement.clone()),
-        TankBundle::new(spawn_location_2, Sprite::from_image(player_2_texture)),
+        TankBundle::new(
+            spawn_location,
+            Sprite::from_image(player_2_texture),
+            &configuration,
+        ),
     ));
 }
 
 fn move_player(
-    mut query: Query<(&mut Transform, &mut Player)>,
+    mut query: Query<(&mut Transform, &mut Tank, &Player)>,
     time: Res<Time>,
     keys: Res<ButtonInput<KeyCode>>,
     configuration: Res<Configuration>,
 ) {
-    for (mut transform, mut player) in query.iter_mut() {
+    for (mut transform, mut tank, player) in query.iter_mut() {
         let mut movement = 0.;
         let mut rotation = 0.;
 
@@ -88,37 +103,51 @@ fn move_player(
             movement -= 1.;
         }
 
-        transform.rotate_z(rotation * configuration.rotation_speed * time.delta_secs());
-
-        let direction = transform.rotation * Vec3::Y;
-        let distance = movement * configuration.move_speed * time.delta_secs();
+        tank.drive(
+            &mut transform,
+            movement,
+            rotation,
+            &configuration,
+            time.delta_secs(),
+        );
+    }
+}
 
-        player.last_pos = transform.translation;
-        transform.translation += direction * distance;
+fn switch_weapon(
+    mut commands: Commands,
+    mut query: Query<(Entity, &Player, &mut Inventory, Option<&Magazine>)>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    for (entity, player, mut inventory, magazine) in query.iter_mut() {
+        if keys.just_pressed(player.controls.next_weapon) {
+            inventory.next(magazine);
+        } else if keys.just_pressed(player.controls.previous_weapon) {
+            inventory.previous(magazine);
+        } else {
+            continue;
+        }
+        inventory.equip(entity, &mut commands);
     }
 }
 
 fn shoot(
-    mut query: Query<(&mut Player, &Transform, &Weapon)>,
+    mut query: Query<(Entity, &mut Player, &Weapon)>,
+    shot_query: Query<&Shot>,
     mut commands: Commands,
-    assets_server: Res<AssetServer>,
-    configuration: Res<Configuration>,
-    resolution: Res<Resolution>,
     keys: Res<ButtonInput<KeyCode>>,
+    weapons: Res<Weapons>,
 ) {
-    for (mut player, transform, weapon) in query.iter_mut() {
+    for (entity, mut player, weapon) in query.iter_mut() {
+        let Some(definition) = weapons.get(weapon) else {
+            continue;
+        };
         let duration = SystemTime::now().duration_since(player.last_shot).unwrap();
-        if keys.pressed(player.controls.shoot)
-            && duration.as_millis() > configuration.shoot_interval
-        {
+        if !keys.pressed(player.controls.shoot) || duration.as_secs_f32() <= definition.cooldown {
+            continue;
+        }
+        let live_projectiles = Shot::count(entity, weapon, &shot_query);
+        if definition.shoot(entity, live_projectiles, &mut commands) {
             player.last_shot = SystemTime::now();
-            weapon.shoot(
-                transform,
-                &assets_server,
-                &mut commands,
-                &configuration,
-                &resolution,
-            );
         }
     }
 }
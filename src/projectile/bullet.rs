@@ -6,7 +6,9 @@ use crate::physic::collision::circle::CircleCollider;
 use crate::physic::collision::collider::Collider;
 use crate::physic::collision::Collision;
 use crate::physic::velocity::Velocity;
+use crate::projectile::lifetime::Lifetime;
 use crate::projectile::Projectile;
+use crate::weapon::definition::WeaponDefinition;
 
 #[derive(Component)]
 pub struct Bullet {}
@@ -15,6 +17,7 @@ pub struct Bullet {}
 pub struct BulletBundle {
     bullet: Bullet,
     collider: Collision,
+    lifetime: Lifetime,
     projectile: Projectile,
     sprite: Sprite,
     velocity: Velocity,
@@ -22,24 +25,29 @@ pub struct BulletBundle {
 
 impl BulletBundle {
     pub fn new(
+        owner: Entity,
         tank_position: &Transform,
+        definition: &WeaponDefinition,
         assets_server: &Res<AssetServer>,
         configuration: &Res<Configuration>,
         resolution: &Res<Resolution>,
     ) -> BulletBundle {
         let direction = tank_position.rotation * Vec3::Y;
-        let bullet_texture = assets_server.load("ammunition/bullet.png");
-        let collider = CircleCollider::new(5., tank_position.translation.xy());
+        let collider = CircleCollider::new(definition.radius, tank_position.translation.xy());
         let position: Vec3 =
             tank_position.translation + tank_position.rotation * configuration.tank_shoot_location;
         let spawn_location = Transform::from_translation(position)
             .with_scale(Vec3::splat(resolution.projectile_pixel_ratio));
-        let velocity = configuration.bullet_speed * direction;
+        let velocity = definition.speed * direction;
         BulletBundle {
             bullet: Bullet {},
             collider: Collision::new(Collider::Circle(collider), spawn_location),
-            projectile: Projectile {},
-            sprite: Sprite::from_image(bullet_texture),
+            lifetime: Lifetime::from_definition(definition),
+            projectile: Projectile {
+                owner,
+                damage: definition.damage,
+            },
+            sprite: definition.projectile_sprite(assets_server),
             velocity: Velocity(velocity),
         }
     }
@@ -0,0 +1,178 @@
+use bevy::color::palettes::css::*;
+use bevy::prelude::*;
+
+use crate::physic::collision::collider::Collider;
+use crate::physic::solid::Solid;
+use crate::physic::solid::SolidHit;
+use crate::tank::Damage;
+use crate::tank::Tank;
+
+pub struct ExplosionPlugin;
+
+impl Plugin for ExplosionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(explode)
+            .add_systems(Update, render_explosions);
+    }
+}
+
+#[derive(Component, Clone)]
+pub struct Explosive {
+    pub damage: f32,
+    pub immune: Option<Entity>,
+    pub knockback: f32,
+    pub lethal_radius: f32,
+    pub radius: f32,
+}
+
+impl Explosive {
+    // Deals the full damage up to the lethal radius and then falls off linearly
+    // to nothing at the radius.
+    pub fn damage_at(&self, distance: f32) -> f32 {
+        self.damage * self.falloff(distance)
+    }
+
+    fn falloff(&self, distance: f32) -> f32 {
+        if distance <= self.lethal_radius {
+            return 1.;
+        }
+        (1. - (distance - self.lethal_radius) / (self.radius - self.lethal_radius)).max(0.)
+    }
+}
+
+// Marks an explosive that is removed without going off, like a forgotten mine.
+#[derive(Component)]
+pub struct Defused;
+
+#[derive(Component)]
+pub struct Explosion {
+    pub radius: f32,
+    pub timer: Timer,
+}
+
+const EXPLOSION_DURATION: f32 = 0.3;
+
+// Explosives go off whenever they are despawned, no matter if they hit a wall, a
+// tank or simply ran out of range, unless they were defused first.
+fn explode(
+    trigger: Trigger<OnRemove, Explosive>,
+    mut commands: Commands,
+    explosive_query: Query<(&Explosive, &Transform), Without<Defused>>,
+    tank_query: Query<(Entity, &Collider), With<Tank>>,
+    solid_query: Query<(Entity, &Collider), With<Solid>>,
+    mut damages: EventWriter<Damage>,
+    mut solid_hits: EventWriter<SolidHit>,
+) {
+    let Ok((explosive, transform)) = explosive_query.get(trigger.target()) else {
+        return;
+    };
+    let origin = transform.translation;
+
+    // Distances are measured to the edges, so a direct hit deals the full damage.
+    for (tank, tank_c) in tank_query.iter() {
+        let distance = tank_c.distance_to(origin.xy());
+        if explosive.immune == Some(tank) || distance > explosive.radius {
+            continue;
+        }
+        damages.write(Damage {
+            tank,
+            amount: explosive.damage_at(distance),
+            origin,
+            knockback: explosive.knockback * explosive.falloff(distance),
+        });
+    }
+
+    // Walls without durability simply ignore the hit.
+    for (solid, solid_c) in solid_query.iter() {
+        if tank_query.contains(solid) {
+            continue;
+        }
+        let distance = solid_c.distance_to(origin.xy());
+        if distance <= explosive.radius {
+            solid_hits.write(SolidHit {
+                solid,
+                damage: explosive.damage_at(distance),
+            });
+        }
+    }
+
+    commands.spawn((
+        Transform::from_translation(origin),
+        Explosion {
+            radius: explosive.radius,
+            timer: Timer::from_seconds(EXPLOSION_DURATION, TimerMode::Once),
+        },
+    ));
+}
+
+fn render_explosions(
+    mut commands: Commands,
+    mut gizmos: Gizmos,
+    mut explosion_query: Query<(Entity, &Transform, &mut Explosion)>,
+    time: Res<Time>,
+) {
+    for (entity, transform, mut explosion) in explosion_query.iter_mut() {
+        explosion.timer.tick(time.delta());
+        if explosion.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let progress = explosion.timer.fraction();
+        let color = ORANGE.with_alpha(1. - progress);
+        gizmos.circle_2d(
+            transform.translation.xy(),
+            explosion.radius * progress.max(0.2),
+            color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mine() -> Explosive {
+        Explosive {
+            damage: 100.,
+            immune: None,
+            knockback: 0.,
+            lethal_radius: 90.,
+            radius: 150.,
+        }
+    }
+
+    fn tank_at(position: Vec3) -> Collider {
+        let mut collider = Tank::collider();
+        collider.update(&Transform::from_translation(position));
+        collider
+    }
+
+    #[test]
+    fn direct_hit_deals_full_damage() {
+        let rocket = Explosive {
+            lethal_radius: 0.,
+            radius: 120.,
+            ..mine()
+        };
+        let tank_c = tank_at(Vec3::ZERO);
+        assert_eq!(
+            rocket.damage_at(tank_c.distance_to(Vec2::new(0., 50.))),
+            100.
+        );
+    }
+
+    #[test]
+    fn mine_kills_at_trigger_distance() {
+        // The tank's side is 25 from its center, so its edge is 90 away.
+        let tank_c = tank_at(Vec3::new(115., 0., 0.));
+        assert!((tank_c.distance_to(Vec2::ZERO) - 90.).abs() < 0.001);
+        assert_eq!(mine().damage_at(tank_c.distance_to(Vec2::ZERO)), 100.);
+    }
+
+    #[test]
+    fn mine_damage_falls_off_beyond_trigger_distance() {
+        assert_eq!(mine().damage_at(120.), 50.);
+        assert_eq!(mine().damage_at(150.), 0.);
+        assert_eq!(mine().damage_at(200.), 0.);
+    }
+}
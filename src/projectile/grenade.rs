@@ -0,0 +1,148 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::configuration::resolution::Resolution;
+use crate::configuration::Configuration;
+use crate::physic::bounce::Bounce;
+use crate::physic::collision::circle::CircleCollider;
+use crate::physic::collision::collider::Collider;
+use crate::physic::collision::Collision;
+use crate::physic::velocity::Velocity;
+use crate::projectile::lifetime::Lifetime;
+use crate::projectile::Projectile;
+use crate::weapon::definition::FragGrenadeStats;
+use crate::weapon::definition::WeaponDefinition;
+
+pub struct GrenadePlugin;
+
+impl Plugin for GrenadePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(detonate)
+            .add_systems(FixedUpdate, slow_down_grenades);
+    }
+}
+
+#[derive(Component)]
+pub struct FragGrenade {
+    fragment_damage: f32,
+    stats: FragGrenadeStats,
+}
+
+#[derive(Component)]
+pub struct Fragment {}
+
+#[derive(Bundle)]
+pub struct FragGrenadeBundle {
+    bounce: Bounce,
+    collider: Collision,
+    frag_grenade: FragGrenade,
+    lifetime: Lifetime,
+    projectile: Projectile,
+    sprite: Sprite,
+    velocity: Velocity,
+}
+
+#[derive(Bundle)]
+pub struct FragmentBundle {
+    collider: Collision,
+    fragment: Fragment,
+    lifetime: Lifetime,
+    projectile: Projectile,
+    sprite: Sprite,
+    velocity: Velocity,
+}
+
+impl FragGrenadeBundle {
+    pub fn new(
+        owner: Entity,
+        tank_position: &Transform,
+        definition: &WeaponDefinition,
+        stats: &FragGrenadeStats,
+        assets_server: &Res<AssetServer>,
+        configuration: &Res<Configuration>,
+        resolution: &Res<Resolution>,
+    ) -> FragGrenadeBundle {
+        let direction = tank_position.rotation * Vec3::Y;
+        let collider = CircleCollider::new(definition.radius, tank_position.translation.xy());
+        let position: Vec3 =
+            tank_position.translation + tank_position.rotation * configuration.tank_shoot_location;
+        let spawn_location = Transform::from_translation(position)
+            .with_scale(Vec3::splat(resolution.ammunition_pixel_ratio));
+        let velocity = definition.speed * direction;
+        FragGrenadeBundle {
+            bounce: Bounce {
+                bounce_count: definition.bounce_count,
+                last_bounce: Entity::PLACEHOLDER,
+            },
+            collider: Collision::new(Collider::Circle(collider), spawn_location),
+            frag_grenade: FragGrenade {
+                fragment_damage: definition.damage,
+                stats: stats.clone(),
+            },
+            lifetime: Lifetime::from_definition(definition),
+            projectile: Projectile { owner, damage: 0. },
+            sprite: definition.projectile_sprite(assets_server),
+            velocity: Velocity(velocity),
+        }
+    }
+}
+
+impl FragmentBundle {
+    pub fn new(
+        owner: Entity,
+        damage: f32,
+        position: Vec3,
+        direction: Vec3,
+        stats: &FragGrenadeStats,
+        assets_server: &Res<AssetServer>,
+        resolution: &Res<Resolution>,
+    ) -> FragmentBundle {
+        let fragment_texture = assets_server.load(&stats.fragment_sprite);
+        let collider = CircleCollider::new(stats.fragment_radius, position.xy());
+        let spawn_location = Transform::from_translation(position)
+            .with_scale(Vec3::splat(resolution.projectile_pixel_ratio));
+        FragmentBundle {
+            collider: Collision::new(Collider::Circle(collider), spawn_location),
+            fragment: Fragment {},
+            lifetime: Lifetime::new(None, Some(stats.fragment_range)),
+            projectile: Projectile { owner, damage },
+            sprite: Sprite::from_image(fragment_texture),
+            velocity: Velocity(stats.fragment_speed * direction),
+        }
+    }
+}
+
+fn slow_down_grenades(mut grenade_query: Query<(&FragGrenade, &mut Velocity)>, time: Res<Time>) {
+    for (grenade, mut velocity) in grenade_query.iter_mut() {
+        **velocity *= (-grenade.stats.drag * time.delta_secs()).exp();
+    }
+}
+
+// Grenades spray their fragments however they are removed, so one that runs out
+// of bounces or touches a tank detonates just like one whose fuse burnt down.
+fn detonate(
+    trigger: Trigger<OnRemove, FragGrenade>,
+    mut commands: Commands,
+    grenade_query: Query<(&FragGrenade, &Projectile, &Transform)>,
+    assets_server: Res<AssetServer>,
+    resolution: Res<Resolution>,
+) {
+    let Ok((grenade, projectile, transform)) = grenade_query.get(trigger.target()) else {
+        return;
+    };
+    let count = grenade.stats.fragment_count;
+    for index in 0..count {
+        let angle = TAU * index as f32 / count as f32;
+        let direction = Quat::from_rotation_z(angle) * Vec3::Y;
+        commands.spawn(FragmentBundle::new(
+            projectile.owner,
+            grenade.fragment_damage,
+            transform.translation,
+            direction,
+            &grenade.stats,
+            &assets_server,
+            &resolution,
+        ));
+    }
+}
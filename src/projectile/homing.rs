@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+
+use crate::configuration::resolution::Resolution;
+use crate::configuration::Configuration;
+use crate::physic::collision::circle::CircleCollider;
+use crate::physic::collision::collider::Collider;
+use crate::physic::collision::Collision;
+use crate::physic::velocity::Velocity;
+use crate::projectile::lifetime::Lifetime;
+use crate::projectile::Projectile;
+use crate::tank::Tank;
+use crate::weapon::definition::HomingMissileStats;
+use crate::weapon::definition::WeaponDefinition;
+
+pub struct HomingPlugin;
+
+impl Plugin for HomingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, steer_homing_missiles);
+    }
+}
+
+#[derive(Component)]
+pub struct HomingMissile {
+    turn_rate: f32,
+}
+
+#[derive(Bundle)]
+pub struct HomingMissileBundle {
+    collider: Collision,
+    homing_missile: HomingMissile,
+    lifetime: Lifetime,
+    projectile: Projectile,
+    sprite: Sprite,
+    velocity: Velocity,
+}
+
+impl HomingMissileBundle {
+    pub fn new(
+        owner: Entity,
+        tank_position: &Transform,
+        definition: &WeaponDefinition,
+        stats: &HomingMissileStats,
+        assets_server: &Res<AssetServer>,
+        configuration: &Res<Configuration>,
+        resolution: &Res<Resolution>,
+    ) -> HomingMissileBundle {
+        let direction = tank_position.rotation * Vec3::Y;
+        let collider = CircleCollider::new(definition.radius, tank_position.translation.xy());
+        let position: Vec3 =
+            tank_position.translation + tank_position.rotation * configuration.tank_shoot_location;
+        let spawn_location = Transform::from_translation(position)
+            .with_rotation(tank_position.rotation)
+            .with_scale(Vec3::splat(resolution.ammunition_pixel_ratio));
+        let velocity = definition.speed * direction;
+        HomingMissileBundle {
+            collider: Collision::new(Collider::Circle(collider), spawn_location),
+            homing_missile: HomingMissile {
+                turn_rate: stats.turn_rate,
+            },
+            lifetime: Lifetime::from_definition(definition),
+            projectile: Projectile {
+                owner,
+                damage: definition.damage,
+            },
+            sprite: definition.projectile_sprite(assets_server),
+            velocity: Velocity(velocity),
+        }
+    }
+}
+
+fn steer_homing_missiles(
+    mut missile_query: Query<
+        (&HomingMissile, &Projectile, &mut Transform, &mut Velocity),
+        Without<Tank>,
+    >,
+    tank_query: Query<(Entity, &Transform), With<Tank>>,
+    time: Res<Time>,
+) {
+    for (missile, projectile, mut transform, mut velocity) in missile_query.iter_mut() {
+        let position = transform.translation.xy();
+        let target = tank_query
+            .iter()
+            .filter(|(tank, _)| *tank != projectile.owner)
+            .map(|(_, tank_transform)| tank_transform.translation.xy())
+            .min_by(|a, b| a.distance(position).total_cmp(&b.distance(position)));
+        let Some(target) = target else {
+            continue;
+        };
+
+        // Turning is capped, so a tank can still shake off the missile by
+        // putting a wall between them.
+        let heading = velocity.xy();
+        let max_turn = missile.turn_rate * time.delta_secs();
+        let turn = heading
+            .angle_to(target - position)
+            .clamp(-max_turn, max_turn);
+        let rotation = Quat::from_rotation_z(turn);
+        **velocity = rotation * **velocity;
+        transform.rotation = rotation * transform.rotation;
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::color::palettes::css::*;
+use bevy::prelude::*;
+
+use crate::configuration::Configuration;
+use crate::physic::collision::collider::Collider;
+use crate::physic::collision::ray::Raycast;
+use crate::physic::solid::Solid;
+use crate::physic::solid::SolidHit;
+use crate::tank::Damage;
+use crate::tank::Tank;
+use crate::weapon::definition::LaserStats;
+use crate::weapon::definition::WeaponDefinition;
+
+pub struct LaserPlugin;
+
+impl Plugin for LaserPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (trace_lasers, render_lasers).chain());
+    }
+}
+
+#[derive(Component)]
+pub struct Laser {
+    owner: Entity,
+    origin: Vec2,
+    direction: Vec2,
+    damage: f32,
+    range: f32,
+    reflections: u32,
+    path: Option<Vec<Vec2>>,
+    timer: Timer,
+}
+
+impl Laser {
+    pub fn new(
+        owner: Entity,
+        tank_position: &Transform,
+        definition: &WeaponDefinition,
+        stats: &LaserStats,
+        configuration: &Res<Configuration>,
+    ) -> Laser {
+        let direction = tank_position.rotation * Vec3::Y;
+        let position: Vec3 =
+            tank_position.translation + tank_position.rotation * configuration.tank_shoot_location;
+        Laser {
+            owner,
+            origin: position.xy(),
+            direction: direction.xy().normalize(),
+            damage: definition.damage,
+            // Without a range in its definition the beam does not reach anywhere.
+            range: definition.range.unwrap_or_default(),
+            reflections: stats.reflections,
+            path: None,
+            timer: Timer::from_seconds(configuration.laser_duration, TimerMode::Once),
+        }
+    }
+}
+
+// The beam is traced once, on the frame after it was fired, and then only
+// rendered until it has faded out.
+fn trace_lasers(
+    mut laser_query: Query<&mut Laser>,
+    solid_query: Query<(Entity, &Collider), With<Solid>>,
+    tank_query: Query<(), With<Tank>>,
+    configuration: Res<Configuration>,
+    mut damages: EventWriter<Damage>,
+    mut solid_hits: EventWriter<SolidHit>,
+) {
+    for mut laser in laser_query.iter_mut() {
+        if laser.path.is_some() {
+            continue;
+        }
+
+        let mut origin = laser.origin;
+        let mut direction = laser.direction;
+        let mut remaining = laser.range;
+        let mut path = vec![origin];
+
+        for reflection in 0..=laser.reflections {
+            let hit = solid_query
+                .iter()
+                .filter(|(entity, _)| reflection > 0 || *entity != laser.owner)
+                .filter_map(|(entity, collider)| {
+                    collider.raycast(origin, direction).map(|hit| (entity, hit))
+                })
+                .filter(|(_, hit)| hit.distance <= remaining)
+                .min_by(|(_, a), (_, b)| a.distance.total_cmp(&b.distance));
+
+            let Some((entity, hit)) = hit else {
+                path.push(origin + direction * remaining);
+                break;
+            };
+            path.push(hit.point);
+
+            if tank_query.contains(entity) {
+                damages.write(Damage {
+                    tank: entity,
+                    amount: laser.damage,
+                    origin: (hit.point - direction).extend(0.),
+                    knockback: configuration.projectile_knockback,
+                });
+                break;
+            }
+
+            solid_hits.write(SolidHit {
+                solid: entity,
+                damage: laser.damage,
+            });
+            remaining -= hit.distance;
+            direction = direction.reflect(hit.normal);
+            origin = hit.point + hit.normal * 0.01;
+        }
+
+        laser.path = Some(path);
+    }
+}
+
+fn render_lasers(
+    mut commands: Commands,
+    mut gizmos: Gizmos,
+    mut laser_query: Query<(Entity, &mut Laser)>,
+    time: Res<Time>,
+) {
+    for (entity, mut laser) in laser_query.iter_mut() {
+        laser.timer.tick(time.delta());
+        if laser.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let Some(path) = &laser.path else {
+            continue;
+        };
+        let color = RED.with_alpha(1. - laser.timer.fraction());
+        gizmos.linestrip_2d(path.iter().copied(), color);
+    }
+}
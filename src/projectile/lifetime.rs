@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+use crate::projectile::explosion::Defused;
+use crate::projectile::mine::Mine;
+use crate::weapon::definition::WeaponDefinition;
+
+pub struct LifetimePlugin;
+
+impl Plugin for LifetimePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, expire_projectiles);
+    }
+}
+
+#[derive(Component)]
+pub struct Lifetime {
+    timer: Option<Timer>,
+    range: Option<f32>,
+    travelled: f32,
+    last_position: Option<Vec3>,
+}
+
+impl Lifetime {
+    pub fn new(duration: Option<f32>, range: Option<f32>) -> Lifetime {
+        Lifetime {
+            timer: duration.map(|duration| Timer::from_seconds(duration, TimerMode::Once)),
+            range,
+            travelled: 0.,
+            last_position: None,
+        }
+    }
+
+    pub fn from_definition(definition: &WeaponDefinition) -> Lifetime {
+        Lifetime::new(definition.lifetime, definition.range)
+    }
+
+    fn expired(&self) -> bool {
+        self.timer.as_ref().is_some_and(Timer::finished)
+            || self.range.is_some_and(|range| self.travelled >= range)
+    }
+}
+
+fn expire_projectiles(
+    mut commands: Commands,
+    mut projectile_query: Query<(Entity, &mut Lifetime, &Transform, Has<Mine>)>,
+    time: Res<Time>,
+) {
+    for (entity, mut lifetime, transform, mine) in projectile_query.iter_mut() {
+        if let Some(timer) = lifetime.timer.as_mut() {
+            timer.tick(time.delta());
+        }
+        // Distance is accumulated so that bouncing projectiles use up their range too.
+        if let Some(last_position) = lifetime.last_position {
+            lifetime.travelled += last_position.distance(transform.translation);
+        }
+        lifetime.last_position = Some(transform.translation);
+
+        // Rockets still explode at the end of their range, old mines just vanish.
+        if lifetime.expired() {
+            if mine {
+                commands.entity(entity).insert(Defused);
+            }
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -8,15 +8,35 @@ use crate::configuration::Configuration;
 use crate::physic::collision::circle::CircleCollider;
 use crate::physic::collision::collider::Collider;
 use crate::physic::collision::Collision;
+use crate::projectile::explosion::Explosive;
+use crate::projectile::lifetime::Lifetime;
 use crate::projectile::Projectile;
+use crate::tank::Tank;
+use crate::weapon::definition::MineStats;
+use crate::weapon::definition::WeaponDefinition;
+
+pub struct MinePlugin;
+
+impl Plugin for MinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (arm_mines, trigger_mines, detonate_mines).chain());
+    }
+}
 
 #[derive(Component)]
-pub struct Mine {}
+pub struct Mine {
+    trigger_radius: f32,
+    arming: Timer,
+    detonation: Option<Timer>,
+    sprite: Sprite,
+}
 
 #[derive(Bundle)]
 pub struct MineBundle {
     collider: Collision,
+    explosive: Explosive,
     hide: Hide,
+    lifetime: Lifetime,
     mine: Mine,
     projectile: Projectile,
     sprite: Sprite,
@@ -24,27 +44,106 @@ pub struct MineBundle {
 
 impl MineBundle {
     pub fn new(
+        owner: Entity,
         tank_position: &Transform,
-        spawn_time: SystemTime,
+        definition: &WeaponDefinition,
+        stats: &MineStats,
         assets_server: &Res<AssetServer>,
         configuration: &Res<Configuration>,
         resolution: &Res<Resolution>,
     ) -> MineBundle {
-        let mine_texture = assets_server.load("ammunition/mine.png");
-        let collider = CircleCollider::new(20., tank_position.translation.xy());
+        let sprite = definition.projectile_sprite(assets_server);
+        let collider = CircleCollider::new(definition.radius, tank_position.translation.xy());
         let position: Vec3 =
             tank_position.translation + tank_position.rotation * configuration.tank_mine_location;
         let spawn_location = Transform::from_translation(position)
             .with_scale(Vec3::splat(resolution.mine_pixel_ratio));
         MineBundle {
             collider: Collision::new(Collider::Circle(collider), spawn_location),
+            explosive: Explosive {
+                damage: definition.damage,
+                immune: configuration.mine_owner_immunity.then_some(owner),
+                knockback: configuration.explosion_knockback,
+                // A tank close enough to trigger the mine dies unless it gets away in time.
+                lethal_radius: stats.trigger_radius,
+                radius: stats.explosion_radius,
+            },
             hide: Hide {
-                spawn_time,
+                spawn_time: SystemTime::now(),
                 visible_duration: configuration.mine_visible_duration,
             },
-            mine: Mine {},
-            projectile: Projectile {},
-            sprite: Sprite::from_image(mine_texture),
+            lifetime: Lifetime::from_definition(definition),
+            mine: Mine {
+                trigger_radius: stats.trigger_radius,
+                arming: Timer::from_seconds(configuration.mine_arming_delay, TimerMode::Once),
+                detonation: None,
+                sprite: sprite.clone(),
+            },
+            projectile: Projectile { owner, damage: 0. },
+            sprite,
         }
     }
 }
+
+fn arm_mines(mut mine_query: Query<&mut Mine>, time: Res<Time>) {
+    for mut mine in mine_query.iter_mut() {
+        mine.arming.tick(time.delta());
+    }
+}
+
+fn trigger_mines(
+    mut commands: Commands,
+    mut mine_query: Query<(Entity, &mut Mine, &Projectile, &Transform)>,
+    tank_query: Query<(Entity, &Collider), With<Tank>>,
+    configuration: Res<Configuration>,
+) {
+    for (entity, mut mine, projectile, transform) in mine_query.iter_mut() {
+        if !mine.arming.finished() || mine.detonation.is_some() {
+            continue;
+        }
+
+        let triggered = tank_query.iter().any(|(tank, tank_c)| {
+            !(configuration.mine_owner_immunity && tank == projectile.owner)
+                && tank_c.distance_to(transform.translation.xy()) <= mine.trigger_radius
+        });
+        if !triggered {
+            continue;
+        }
+
+        // The mine may already be hidden, so it is shown again for the warning. A
+        // bullet can despawn it in the same frame, hence the fallible commands.
+        mine.detonation = Some(Timer::from_seconds(
+            configuration.mine_warning_duration,
+            TimerMode::Once,
+        ));
+        commands
+            .entity(entity)
+            .try_remove::<Hide>()
+            .try_insert(mine.sprite.clone());
+    }
+}
+
+fn detonate_mines(
+    mut commands: Commands,
+    mut mine_query: Query<(Entity, &mut Mine, &mut Visibility)>,
+    configuration: Res<Configuration>,
+    time: Res<Time>,
+) {
+    for (entity, mut mine, mut visibility) in mine_query.iter_mut() {
+        let Some(detonation) = mine.detonation.as_mut() else {
+            continue;
+        };
+        detonation.tick(time.delta());
+        if detonation.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let blink = (detonation.elapsed_secs() / configuration.mine_blink_interval) as u32;
+        *visibility = if blink.is_multiple_of(2) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
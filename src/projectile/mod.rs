@@ -1,33 +1,137 @@
 use bevy::prelude::*;
 
+use crate::configuration::Configuration;
 use crate::physic::bounce::Bounce;
 use crate::physic::collision::collider::Collider;
 use crate::physic::solid::Solid;
+use crate::physic::solid::SolidHit;
+use crate::physic::velocity::Velocity;
+use crate::projectile::bullet::Bullet;
+use crate::projectile::mine::Mine;
+use crate::projectile::shuriken::Shuriken;
 
 pub mod bullet;
+pub mod explosion;
+pub mod grenade;
+pub mod homing;
+pub mod laser;
+pub mod lifetime;
 pub mod mine;
+pub mod rocket;
 pub mod shuriken;
 
 pub struct ProjectilePlugin;
 
 impl Plugin for ProjectilePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, check_solid_collision);
+        app.add_plugins((
+            explosion::ExplosionPlugin,
+            grenade::GrenadePlugin,
+            homing::HomingPlugin,
+            laser::LaserPlugin,
+            lifetime::LifetimePlugin,
+            mine::MinePlugin,
+            rocket::RocketPlugin,
+        ))
+        .add_systems(
+            Update,
+            (
+                check_solid_collision,
+                check_projectile_collision,
+                deflect_shurikens,
+            ),
+        );
     }
 }
 
 #[derive(Component)]
-pub struct Projectile {}
+pub struct Projectile {
+    pub owner: Entity,
+    pub damage: f32,
+}
 
 fn check_solid_collision(
     mut commands: Commands,
-    projectile_query: Query<(Entity, &Collider), (With<Projectile>, Without<Bounce>)>,
-    solid_query: Query<&Collider, With<Solid>>,
+    projectile_query: Query<(Entity, &Projectile, &Collider), Without<Bounce>>,
+    solid_query: Query<(Entity, &Collider), With<Solid>>,
+    mine_query: Query<(), With<Mine>>,
+    mut solid_hits: EventWriter<SolidHit>,
 ) {
-    for (projectile, projectile_c) in projectile_query.iter() {
-        for wall_c in solid_query.iter() {
+    for (entity, projectile, projectile_c) in projectile_query.iter() {
+        // Mines only go off through their trigger or when shot.
+        if mine_query.contains(entity) {
+            continue;
+        }
+        for (wall, wall_c) in solid_query.iter() {
             if projectile_c.intersects(wall_c) {
-                commands.entity(projectile).despawn();
+                solid_hits.write(SolidHit {
+                    solid: wall,
+                    damage: projectile.damage,
+                });
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+fn check_projectile_collision(
+    mut commands: Commands,
+    bullet_query: Query<(Entity, &Projectile, &Collider), With<Bullet>>,
+    mine_query: Query<(Entity, &Projectile, &Collider), With<Mine>>,
+    configuration: Res<Configuration>,
+) {
+    let mut pairs = Vec::new();
+    if configuration.bullets_cancel {
+        pairs.extend(
+            bullet_query
+                .iter_combinations()
+                .map(|[first, second]| (first, second)),
+        );
+    }
+    if configuration.bullets_detonate_mines {
+        pairs.extend(
+            bullet_query
+                .iter()
+                .flat_map(|bullet| mine_query.iter().map(move |mine| (bullet, mine))),
+        );
+    }
+
+    let mut destroyed = Vec::new();
+    for ((first, first_p, first_c), (second, second_p, second_c)) in pairs {
+        if first_p.owner != second_p.owner
+            && !destroyed.contains(&first)
+            && !destroyed.contains(&second)
+            && first_c.intersects(second_c)
+        {
+            destroyed.extend([first, second]);
+        }
+    }
+
+    for entity in destroyed {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn deflect_shurikens(
+    mut shuriken_query: Query<(&mut Velocity, &Projectile, &Collider), With<Shuriken>>,
+    mine_query: Query<(&Projectile, &Collider), With<Mine>>,
+    configuration: Res<Configuration>,
+) {
+    if !configuration.shurikens_deflect_off_mines {
+        return;
+    }
+    for (mut velocity, shuriken_p, shuriken_c) in shuriken_query.iter_mut() {
+        for (mine_p, mine_c) in mine_query.iter() {
+            if shuriken_p.owner == mine_p.owner || !shuriken_c.intersects(mine_c) {
+                continue;
+            }
+            // Only shurikens still heading into the mine are turned around, so an
+            // overlap lasting several frames does not flip them back and forth.
+            let normal = (shuriken_c.center() - mine_c.center())
+                .normalize_or_zero()
+                .extend(0.);
+            if velocity.dot(normal) < 0. {
+                **velocity = velocity.reflect(normal);
             }
         }
     }
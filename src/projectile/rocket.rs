@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+use crate::configuration::resolution::Resolution;
+use crate::configuration::Configuration;
+use crate::physic::collision::circle::CircleCollider;
+use crate::physic::collision::collider::Collider;
+use crate::physic::collision::Collision;
+use crate::physic::velocity::Velocity;
+use crate::projectile::explosion::Explosive;
+use crate::projectile::lifetime::Lifetime;
+use crate::projectile::Projectile;
+use crate::weapon::definition::RocketStats;
+use crate::weapon::definition::WeaponDefinition;
+
+pub struct RocketPlugin;
+
+impl Plugin for RocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, accelerate_rockets);
+    }
+}
+
+#[derive(Component)]
+pub struct Rocket {
+    acceleration: f32,
+    max_speed: f32,
+}
+
+#[derive(Bundle)]
+pub struct RocketBundle {
+    collider: Collision,
+    explosive: Explosive,
+    lifetime: Lifetime,
+    projectile: Projectile,
+    rocket: Rocket,
+    sprite: Sprite,
+    velocity: Velocity,
+}
+
+impl RocketBundle {
+    pub fn new(
+        owner: Entity,
+        tank_position: &Transform,
+        definition: &WeaponDefinition,
+        stats: &RocketStats,
+        assets_server: &Res<AssetServer>,
+        configuration: &Res<Configuration>,
+        resolution: &Res<Resolution>,
+    ) -> RocketBundle {
+        let direction = tank_position.rotation * Vec3::Y;
+        let collider = CircleCollider::new(definition.radius, tank_position.translation.xy());
+        let position: Vec3 =
+            tank_position.translation + tank_position.rotation * configuration.tank_shoot_location;
+        let spawn_location = Transform::from_translation(position)
+            .with_rotation(tank_position.rotation)
+            .with_scale(Vec3::splat(resolution.ammunition_pixel_ratio));
+        let velocity = definition.speed * direction;
+        RocketBundle {
+            collider: Collision::new(Collider::Circle(collider), spawn_location),
+            explosive: Explosive {
+                damage: definition.damage,
+                immune: None,
+                knockback: configuration.explosion_knockback,
+                lethal_radius: 0.,
+                radius: stats.explosion_radius,
+            },
+            lifetime: Lifetime::from_definition(definition),
+            projectile: Projectile { owner, damage: 0. },
+            rocket: Rocket {
+                acceleration: stats.acceleration,
+                max_speed: stats.max_speed,
+            },
+            sprite: definition.projectile_sprite(assets_server),
+            velocity: Velocity(velocity),
+        }
+    }
+}
+
+fn accelerate_rockets(mut rocket_query: Query<(&Rocket, &mut Velocity)>, time: Res<Time>) {
+    for (rocket, mut velocity) in rocket_query.iter_mut() {
+        let direction = velocity.normalize_or_zero();
+        let speed =
+            (velocity.length() + rocket.acceleration * time.delta_secs()).min(rocket.max_speed);
+        **velocity = direction * speed;
+    }
+}
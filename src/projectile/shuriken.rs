@@ -6,16 +6,22 @@ use crate::physic::bounce::Bounce;
 use crate::physic::collision::circle::CircleCollider;
 use crate::physic::collision::collider::Collider;
 use crate::physic::collision::Collision;
+use crate::physic::velocity::AngularVelocity;
 use crate::physic::velocity::Velocity;
+use crate::projectile::lifetime::Lifetime;
 use crate::projectile::Projectile;
+use crate::weapon::definition::ShurikenStats;
+use crate::weapon::definition::WeaponDefinition;
 
 #[derive(Component)]
 pub struct Shuriken {}
 
 #[derive(Bundle)]
 pub struct ShurikenBundle {
+    angular_velocity: AngularVelocity,
     bounce: Bounce,
     collider: Collision,
+    lifetime: Lifetime,
     projectile: Projectile,
     shuriken: Shuriken,
     sprite: Sprite,
@@ -24,28 +30,35 @@ pub struct ShurikenBundle {
 
 impl ShurikenBundle {
     pub fn new(
+        owner: Entity,
         tank_position: &Transform,
+        definition: &WeaponDefinition,
+        stats: &ShurikenStats,
         assets_server: &Res<AssetServer>,
         configuration: &Res<Configuration>,
         resolution: &Res<Resolution>,
     ) -> ShurikenBundle {
         let direction = tank_position.rotation * Vec3::Y;
-        let shuriken_texture = assets_server.load("ammunition/shuriken.png");
-        let collider = CircleCollider::new(5., tank_position.translation.xy());
+        let collider = CircleCollider::new(definition.radius, tank_position.translation.xy());
         let position: Vec3 =
             tank_position.translation + tank_position.rotation * configuration.tank_shoot_location;
         let spawn_location = Transform::from_translation(position)
             .with_scale(Vec3::splat(resolution.shuriken_pixel_ratio));
-        let velocity = configuration.shuriken_speed * direction;
+        let velocity = definition.speed * direction;
         ShurikenBundle {
+            angular_velocity: AngularVelocity(stats.spin),
             bounce: Bounce {
-                bounce_count: configuration.shuriken_bounce_count,
+                bounce_count: definition.bounce_count,
                 last_bounce: Entity::PLACEHOLDER,
             },
             collider: Collision::new(Collider::Circle(collider), spawn_location),
-            projectile: Projectile {},
+            lifetime: Lifetime::from_definition(definition),
+            projectile: Projectile {
+                owner,
+                damage: definition.damage,
+            },
             shuriken: Shuriken {},
-            sprite: Sprite::from_image(shuriken_texture),
+            sprite: definition.projectile_sprite(assets_server),
             velocity: Velocity(velocity),
         }
     }
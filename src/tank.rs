@@ -1,31 +1,98 @@
 use bevy::prelude::*;
 
+use crate::ability::shield::Shield;
 use crate::configuration::Configuration;
 use crate::physic::collision::collider::Collider;
 use crate::physic::collision::polygon::PolygonCollider;
 use crate::physic::collision::Collision;
+use crate::physic::impulse::Impulse;
 use crate::physic::solid::Solid;
+use crate::weapon::inventory::Inventory;
 use crate::weapon::Weapon;
 
 pub struct TankPlugin;
 
 impl Plugin for TankPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(FixedUpdate, check_spawn_protection);
+        app.add_event::<Damage>()
+            .add_event::<Killed>()
+            .add_systems(PreUpdate, apply_damage)
+            .add_systems(FixedUpdate, check_spawn_protection);
     }
 }
 
 #[derive(Component)]
 pub struct Tank {
     pub deaths: i32,
+    pub health: f32,
     pub killable: bool,
+    pub last_position: Vec3,
     pub last_time_killed: f32,
-    pub spawn_location: Transform,
+    pub max_health: f32,
+}
+
+#[derive(Event)]
+pub struct Damage {
+    pub tank: Entity,
+    pub amount: f32,
+    pub origin: Vec3,
+    pub knockback: f32,
+}
+
+#[derive(Event)]
+pub struct Killed {
+    pub tank: Entity,
+    pub position: Vec3,
+}
+
+impl Tank {
+    pub fn collider() -> Collider {
+        Collider::Polygon(PolygonCollider::new(Box::new([
+            Vec2::new(-25., -25.),
+            Vec2::new(-25., 25.),
+            Vec2::new(0., 50.),
+            Vec2::new(25., 25.),
+            Vec2::new(25., -25.),
+        ])))
+    }
+
+    pub fn kill(&mut self, impulse: &mut Impulse, now: f32) {
+        self.deaths += 1;
+        self.health = self.max_health;
+        self.killable = false;
+        self.last_time_killed = now;
+        **impulse = Vec3::ZERO;
+    }
+
+    // `movement` and `rotation` range from -1 to 1, whether they come from the
+    // keyboard or from an AI controller.
+    pub fn drive(
+        &mut self,
+        transform: &mut Transform,
+        movement: f32,
+        rotation: f32,
+        configuration: &Configuration,
+        delta: f32,
+    ) {
+        transform.rotate_z(rotation * configuration.rotation_speed * delta);
+
+        let direction = transform.rotation * Vec3::Y;
+        let distance = movement * configuration.move_speed * delta;
+
+        self.last_position = transform.translation;
+        transform.translation += direction * distance;
+    }
+
+    pub fn set_to_last_position(&self, transform: &mut Transform) {
+        transform.translation = self.last_position;
+    }
 }
 
 #[derive(Bundle)]
 pub struct TankBundle {
     collider: Collision,
+    impulse: Impulse,
+    inventory: Inventory,
     solid: Solid,
     sprite: Sprite,
     tank: Tank,
@@ -33,25 +100,26 @@ pub struct TankBundle {
 }
 
 impl TankBundle {
-    pub fn new(spawn_location: Transform, sprite: Sprite) -> TankBundle {
-        let collider = PolygonCollider::new(Box::new([
-            Vec2::new(-25., -25.),
-            Vec2::new(-25., 25.),
-            Vec2::new(0., 50.),
-            Vec2::new(25., 25.),
-            Vec2::new(25., -25.),
-        ]));
+    pub fn new(
+        spawn_location: Transform,
+        sprite: Sprite,
+        configuration: &Configuration,
+    ) -> TankBundle {
         TankBundle {
-            collider: Collision::new(Collider::Polygon(collider), spawn_location),
+            collider: Collision::new(Tank::collider(), spawn_location),
+            impulse: Impulse::default(),
+            inventory: Inventory::new(Weapon::default(configuration), configuration.inventory_size),
             solid: Solid {},
             sprite,
             tank: Tank {
                 deaths: 0,
+                health: configuration.tank_health,
                 killable: false,
+                last_position: spawn_location.translation,
                 last_time_killed: 0.,
-                spawn_location,
+                max_health: configuration.tank_health,
             },
-            weapon: Weapon::default(),
+            weapon: Weapon::default(configuration),
         }
     }
 }
@@ -62,10 +130,39 @@ fn check_spawn_protection(
     configuration: Res<Configuration>,
 ) {
     for mut tank in &mut tank_query {
-        if time.delta_secs() > tank.last_time_killed + configuration.spawn_protection
-            && tank.killable == false
+        if time.elapsed_secs() > tank.last_time_killed + configuration.spawn_protection
+            && !tank.killable
         {
             tank.killable = true;
         }
     }
 }
+
+fn apply_damage(
+    mut damages: EventReader<Damage>,
+    mut kills: EventWriter<Killed>,
+    mut tank_query: Query<(&mut Tank, &Transform, &mut Impulse, Option<&mut Shield>)>,
+    time: Res<Time>,
+) {
+    for damage in damages.read() {
+        let Ok((mut tank, transform, mut impulse, shield)) = tank_query.get_mut(damage.tank) else {
+            continue;
+        };
+        // Contact hits of explosive projectiles carry no damage and only push, so
+        // they must not use up a shield charge.
+        if damage.amount > 0. && tank.killable && !shield.is_some_and(|mut shield| shield.absorb())
+        {
+            tank.health -= damage.amount;
+            if tank.health <= 0. {
+                kills.write(Killed {
+                    tank: damage.tank,
+                    position: transform.translation,
+                });
+                tank.kill(&mut impulse, time.elapsed_secs());
+                continue;
+            }
+        }
+        let position = transform.translation;
+        impulse.push_from(damage.origin, position, damage.knockback);
+    }
+}
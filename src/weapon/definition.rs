@@ -0,0 +1,272 @@
+use std::error::Error;
+
+use bevy::asset::io::Reader;
+use bevy::asset::ron;
+use bevy::asset::AssetLoader;
+use bevy::asset::LoadContext;
+use bevy::prelude::*;
+use rand::random_range;
+use serde::Deserialize;
+
+use crate::weapon::Weapon;
+
+pub struct DefinitionPlugin;
+
+impl Plugin for DefinitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<WeaponDefinitions>()
+            .init_asset_loader::<WeaponDefinitionsLoader>()
+            .add_systems(PreStartup, load_weapon_definitions)
+            .add_systems(PreUpdate, update_weapons);
+    }
+}
+
+// Each kind carries the stats only its projectiles use.
+#[derive(Deserialize, Clone)]
+pub enum WeaponKind {
+    Bullet,
+    FragGrenade(FragGrenadeStats),
+    HomingMissile(HomingMissileStats),
+    Laser(LaserStats),
+    Mine(MineStats),
+    Rocket(RocketStats),
+    Shuriken(ShurikenStats),
+}
+
+#[derive(Deserialize, Clone)]
+pub struct FragGrenadeStats {
+    pub drag: f32,
+    pub fragment_count: u32,
+    pub fragment_speed: f32,
+    pub fragment_range: f32,
+    pub fragment_radius: f32,
+    pub fragment_sprite: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct HomingMissileStats {
+    pub turn_rate: f32,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct LaserStats {
+    pub reflections: u32,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct MineStats {
+    pub trigger_radius: f32,
+    pub explosion_radius: f32,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct RocketStats {
+    pub acceleration: f32,
+    pub max_speed: f32,
+    pub explosion_radius: f32,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ShurikenStats {
+    pub spin: f32,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct WeaponDefinition {
+    pub name: String,
+    pub kind: WeaponKind,
+    #[serde(default)]
+    pub projectile_sprite: Option<String>,
+    #[serde(default = "default_color")]
+    pub projectile_color: (f32, f32, f32),
+    pub chest_texture: String,
+    #[serde(default = "default_color")]
+    pub chest_color: (f32, f32, f32),
+    #[serde(default)]
+    pub speed: f32,
+    #[serde(default)]
+    pub radius: f32,
+    #[serde(default)]
+    pub bounce_count: u32,
+    #[serde(default)]
+    pub lifetime: Option<f32>,
+    #[serde(default)]
+    pub range: Option<f32>,
+    pub cooldown: f32,
+    #[serde(default)]
+    pub ammo: Option<u32>,
+    #[serde(default)]
+    pub max_projectiles: Option<u32>,
+    pub damage: f32,
+    #[serde(default = "default_burst_size")]
+    pub burst_size: u32,
+    #[serde(default)]
+    pub burst_interval: f32,
+    #[serde(default)]
+    pub spread: f32,
+    #[serde(default = "default_spawn_weight")]
+    pub spawn_weight: f32,
+}
+
+fn default_color() -> (f32, f32, f32) {
+    (1., 1., 1.)
+}
+
+fn default_burst_size() -> u32 {
+    1
+}
+
+fn default_spawn_weight() -> f32 {
+    1.
+}
+
+impl WeaponDefinition {
+    pub fn projectile_sprite(&self, assets_server: &AssetServer) -> Sprite {
+        let (red, green, blue) = self.projectile_color;
+        let sprite = match &self.projectile_sprite {
+            Some(path) => Sprite::from_image(assets_server.load(path)),
+            None => Sprite::default(),
+        };
+        Sprite {
+            color: Color::srgb(red, green, blue),
+            ..sprite
+        }
+    }
+
+    // How far a projectile gets before its range or its lifetime runs out, if
+    // anything limits it at all.
+    pub fn reach(&self) -> Option<f32> {
+        let flight = self.lifetime.map(|lifetime| match &self.kind {
+            // Drag slows grenades down exponentially, so they stop well short of
+            // speed times lifetime.
+            WeaponKind::FragGrenade(stats) if stats.drag > 0. => {
+                self.speed / stats.drag * (1. - (-stats.drag * lifetime).exp())
+            }
+            WeaponKind::Rocket(stats) => self.speed.max(stats.max_speed) * lifetime,
+            _ => self.speed * lifetime,
+        });
+        match (self.range, flight) {
+            (Some(range), Some(flight)) => Some(range.min(flight)),
+            (range, flight) => range.or(flight),
+        }
+    }
+
+    pub fn chest_color(&self) -> Color {
+        let (red, green, blue) = self.chest_color;
+        Color::srgb(red, green, blue)
+    }
+}
+
+#[derive(Asset, TypePath, Deserialize, Clone)]
+pub struct WeaponDefinitions {
+    pub weapons: Vec<WeaponDefinition>,
+}
+
+#[derive(Resource)]
+pub struct WeaponDefinitionsHandle(Handle<WeaponDefinitions>);
+
+// The loaded definitions are copied into a resource so systems can look them
+// up without going through the asset storage, and replaced on hot reload.
+#[derive(Resource, Deref)]
+pub struct Weapons(WeaponDefinitions);
+
+impl Weapons {
+    pub fn get(&self, weapon: &Weapon) -> Option<&WeaponDefinition> {
+        self.weapons
+            .iter()
+            .find(|definition| definition.name == weapon.name)
+    }
+
+    pub fn random(&self) -> Option<Weapon> {
+        let total: f32 = self
+            .weapons
+            .iter()
+            .map(|definition| definition.spawn_weight)
+            .sum();
+        if total <= 0. {
+            return None;
+        }
+        let mut roll = random_range(0. ..total);
+        for definition in self.weapons.iter() {
+            if roll < definition.spawn_weight {
+                return Some(Weapon::new(&definition.name));
+            }
+            roll -= definition.spawn_weight;
+        }
+        None
+    }
+}
+
+#[derive(Default, TypePath)]
+pub struct WeaponDefinitionsLoader;
+
+impl AssetLoader for WeaponDefinitionsLoader {
+    type Asset = WeaponDefinitions;
+    type Settings = ();
+    type Error = Box<dyn Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes::<WeaponDefinitions>(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["weapons.ron"]
+    }
+}
+
+fn load_weapon_definitions(mut commands: Commands, assets_server: Res<AssetServer>) {
+    let handle = assets_server.load("weapons/default.weapons.ron");
+    commands.insert_resource(WeaponDefinitionsHandle(handle));
+}
+
+fn update_weapons(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<WeaponDefinitions>>,
+    handle: Res<WeaponDefinitionsHandle>,
+    definitions: Res<Assets<WeaponDefinitions>>,
+) {
+    for event in events.read() {
+        if !event.is_loaded_with_dependencies(&handle.0) && !event.is_modified(&handle.0) {
+            continue;
+        }
+        if let Some(definitions) = definitions.get(&handle.0) {
+            commands.insert_resource(Weapons(definitions.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_definitions_parse() {
+        let bytes = std::fs::read("assets/weapons/default.weapons.ron").unwrap();
+        let definitions = ron::de::from_bytes::<WeaponDefinitions>(&bytes).unwrap();
+        assert!(definitions
+            .weapons
+            .iter()
+            .any(|definition| matches!(definition.kind, WeaponKind::Rocket(_))));
+    }
+
+    #[test]
+    fn grenade_reach_accounts_for_drag() {
+        let bytes = std::fs::read("assets/weapons/default.weapons.ron").unwrap();
+        let definitions = ron::de::from_bytes::<WeaponDefinitions>(&bytes).unwrap();
+        let grenade = definitions
+            .weapons
+            .iter()
+            .find(|definition| matches!(definition.kind, WeaponKind::FragGrenade(_)))
+            .unwrap();
+        let reach = grenade.reach().unwrap();
+        assert!(reach < grenade.speed * grenade.lifetime.unwrap());
+        assert!((reach - 380.).abs() < 1.);
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use crate::player::Player;
+use crate::weapon::inventory::Inventory;
+use crate::weapon::Magazine;
+
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_ammo_text)
+            .add_systems(Update, update_ammo_text);
+    }
+}
+
+#[derive(Component)]
+pub struct AmmoText;
+
+fn spawn_ammo_text(mut commands: Commands) {
+    commands.spawn((
+        AmmoText,
+        Text::default(),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            right: Val::Px(12.0),
+            ..default()
+        },
+    ));
+}
+
+fn update_ammo_text(
+    mut text: Single<&mut Text, With<AmmoText>>,
+    player_query: Query<(Entity, &Inventory, Option<&Magazine>), With<Player>>,
+) {
+    let mut players: Vec<_> = player_query.iter().collect();
+    players.sort_by_key(|(entity, _, _)| *entity);
+
+    text.clear();
+    for (index, (_, inventory, magazine)) in players.into_iter().enumerate() {
+        text.push_str(&format!("Player {}:", index + 1));
+        for (slot_index, slot) in inventory.slots().iter().enumerate() {
+            let active = slot_index == inventory.active();
+            let ammo = if active {
+                magazine.map(|magazine| **magazine)
+            } else {
+                slot.ammo
+            };
+            let ammo = ammo.map_or("inf".to_string(), |ammo| ammo.to_string());
+            if active {
+                text.push_str(&format!(" [{} {}]", slot.weapon.name, ammo));
+            } else {
+                text.push_str(&format!(" {} {}", slot.weapon.name, ammo));
+            }
+        }
+        text.push('\n');
+    }
+}
@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+
+use crate::weapon::Burst;
+use crate::weapon::Magazine;
+use crate::weapon::Weapon;
+
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, drop_empty_weapons);
+    }
+}
+
+pub struct Slot {
+    pub weapon: Weapon,
+    pub ammo: Option<u32>,
+}
+
+// The active slot's ammunition lives in the tank's `Magazine` while it is
+// equipped and is only written back here when switching away from it.
+#[derive(Component)]
+pub struct Inventory {
+    slots: Vec<Slot>,
+    active: usize,
+    capacity: usize,
+}
+
+impl Inventory {
+    pub fn new(default: Weapon, capacity: usize) -> Inventory {
+        Inventory {
+            slots: vec![Slot {
+                weapon: default,
+                ammo: None,
+            }],
+            active: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    pub fn slots(&self) -> &[Slot] {
+        &self.slots
+    }
+
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    pub fn next(&mut self, magazine: Option<&Magazine>) {
+        self.select((self.active + 1) % self.slots.len(), magazine);
+    }
+
+    pub fn previous(&mut self, magazine: Option<&Magazine>) {
+        self.select(
+            (self.active + self.slots.len() - 1) % self.slots.len(),
+            magazine,
+        );
+    }
+
+    // Returns whether the active slot changed and has to be equipped again.
+    pub fn add(&mut self, weapon: Weapon, ammo: Option<u32>, magazine: Option<&Magazine>) -> bool {
+        if let Some(index) = self
+            .slots
+            .iter()
+            .position(|slot| slot.weapon.name == weapon.name)
+        {
+            self.slots[index].ammo = ammo;
+            return index == self.active;
+        }
+
+        let slot = Slot { weapon, ammo };
+        if self.slots.len() < self.capacity {
+            self.slots.push(slot);
+            return false;
+        }
+        // The default weapon in the first slot is never given up.
+        let index = if self.active == 0 {
+            self.slots.len() - 1
+        } else {
+            self.active
+        };
+        if index == 0 {
+            return false;
+        }
+        self.store(magazine);
+        self.slots[index] = slot;
+        index == self.active
+    }
+
+    pub fn equip(&self, tank: Entity, commands: &mut Commands) {
+        let slot = &self.slots[self.active];
+        let mut entity = commands.entity(tank);
+        entity.insert(slot.weapon.clone()).remove::<Burst>();
+        match slot.ammo {
+            Some(ammo) => entity.insert(Magazine(ammo)),
+            None => entity.remove::<Magazine>(),
+        };
+    }
+
+    pub fn take_active(&mut self, magazine: Option<&Magazine>) -> Option<Slot> {
+        if self.active == 0 {
+            return None;
+        }
+        self.store(magazine);
+        let slot = self.slots.remove(self.active);
+        self.active = 0;
+        Some(slot)
+    }
+
+    fn select(&mut self, index: usize, magazine: Option<&Magazine>) {
+        self.store(magazine);
+        self.active = index;
+    }
+
+    fn store(&mut self, magazine: Option<&Magazine>) {
+        if let Some(magazine) = magazine {
+            self.slots[self.active].ammo = Some(**magazine);
+        }
+    }
+}
+
+fn drop_empty_weapons(
+    mut commands: Commands,
+    mut tank_query: Query<(Entity, &mut Inventory, &Magazine), Changed<Magazine>>,
+) {
+    for (tank, mut inventory, magazine) in tank_query.iter_mut() {
+        if magazine.is_empty() {
+            inventory.take_active(None);
+            inventory.equip(tank, &mut commands);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use bevy::prelude::*;
+use rand::random_range;
+
+use crate::configuration::resolution::Resolution;
+use crate::configuration::Configuration;
+use crate::projectile::bullet::BulletBundle;
+use crate::projectile::grenade::FragGrenadeBundle;
+use crate::projectile::homing::HomingMissileBundle;
+use crate::projectile::laser::Laser;
+use crate::projectile::mine::MineBundle;
+use crate::projectile::rocket::RocketBundle;
+use crate::projectile::shuriken::ShurikenBundle;
+use crate::weapon::definition::WeaponDefinition;
+use crate::weapon::definition::WeaponKind;
+use crate::weapon::definition::Weapons;
+
+pub mod definition;
+pub mod hud;
+pub mod inventory;
+
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            definition::DefinitionPlugin,
+            hud::HudPlugin,
+            inventory::InventoryPlugin,
+        ))
+        .add_systems(Update, fire_burst.run_if(resource_exists::<Weapons>));
+    }
+}
+
+#[derive(Component, Deref, DerefMut)]
+pub struct Magazine(pub u32);
+
+#[derive(Component)]
+pub struct Burst {
+    remaining: u32,
+    next_shot: f32,
+}
+
+#[derive(Component)]
+pub struct Shot {
+    owner: Entity,
+    weapon: String,
+}
+
+#[derive(Component, Clone)]
+pub struct Weapon {
+    pub name: String,
+}
+
+impl Weapon {
+    pub fn new(name: &str) -> Weapon {
+        Weapon {
+            name: name.to_string(),
+        }
+    }
+
+    pub fn default(configuration: &Configuration) -> Weapon {
+        Weapon::new(&configuration.default_weapon)
+    }
+}
+
+impl Magazine {
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl WeaponDefinition {
+    pub fn shoot(&self, tank: Entity, live_projectiles: usize, commands: &mut Commands) -> bool {
+        let free = self.max_projectiles.map_or(u32::MAX, |max_projectiles| {
+            max_projectiles.saturating_sub(live_projectiles as u32)
+        });
+        if free == 0 {
+            return false;
+        }
+        commands.entity(tank).insert(Burst {
+            remaining: self.burst_size.min(free),
+            next_shot: 0.,
+        });
+        true
+    }
+
+    fn fire(
+        &self,
+        tank: Entity,
+        transform: &Transform,
+        assets_server: &Res<AssetServer>,
+        commands: &mut Commands,
+        configuration: &Res<Configuration>,
+        resolution: &Res<Resolution>,
+    ) {
+        let mut shot = match &self.kind {
+            WeaponKind::Bullet => commands.spawn(BulletBundle::new(
+                tank,
+                transform,
+                self,
+                assets_server,
+                configuration,
+                resolution,
+            )),
+            WeaponKind::FragGrenade(stats) => commands.spawn(FragGrenadeBundle::new(
+                tank,
+                transform,
+                self,
+                stats,
+                assets_server,
+                configuration,
+                resolution,
+            )),
+            WeaponKind::HomingMissile(stats) => commands.spawn(HomingMissileBundle::new(
+                tank,
+                transform,
+                self,
+                stats,
+                assets_server,
+                configuration,
+                resolution,
+            )),
+            WeaponKind::Laser(stats) => {
+                commands.spawn(Laser::new(tank, transform, self, stats, configuration))
+            }
+            WeaponKind::Mine(stats) => commands.spawn(MineBundle::new(
+                tank,
+                transform,
+                self,
+                stats,
+                assets_server,
+                configuration,
+                resolution,
+            )),
+            WeaponKind::Rocket(stats) => commands.spawn(RocketBundle::new(
+                tank,
+                transform,
+                self,
+                stats,
+                assets_server,
+                configuration,
+                resolution,
+            )),
+            WeaponKind::Shuriken(stats) => commands.spawn(ShurikenBundle::new(
+                tank,
+                transform,
+                self,
+                stats,
+                assets_server,
+                configuration,
+                resolution,
+            )),
+        };
+        shot.insert(Shot {
+            owner: tank,
+            weapon: self.name.clone(),
+        });
+    }
+}
+
+impl Shot {
+    pub fn count(owner: Entity, weapon: &Weapon, shot_query: &Query<&Shot>) -> usize {
+        shot_query
+            .iter()
+            .filter(|shot| shot.owner == owner && shot.weapon == weapon.name)
+            .count()
+    }
+}
+
+fn fire_burst(
+    mut commands: Commands,
+    mut tank_query: Query<(
+        Entity,
+        &Transform,
+        &Weapon,
+        &mut Burst,
+        Option<&mut Magazine>,
+    )>,
+    assets_server: Res<AssetServer>,
+    configuration: Res<Configuration>,
+    resolution: Res<Resolution>,
+    weapons: Res<Weapons>,
+    time: Res<Time>,
+) {
+    for (tank, transform, weapon, mut burst, mut magazine) in tank_query.iter_mut() {
+        let Some(definition) = weapons.get(weapon) else {
+            continue;
+        };
+        burst.next_shot -= time.delta_secs();
+        while burst.next_shot <= 0. && burst.remaining > 0 {
+            if magazine
+                .as_ref()
+                .is_some_and(|magazine| magazine.is_empty())
+            {
+                break;
+            }
+            let spread = random_range(-definition.spread..=definition.spread);
+            let mut aim = *transform;
+            aim.rotate_z(spread);
+            definition.fire(
+                tank,
+                &aim,
+                &assets_server,
+                &mut commands,
+                &configuration,
+                &resolution,
+            );
+            if let Some(magazine) = magazine.as_mut() {
+                ***magazine -= 1;
+            }
+            burst.remaining -= 1;
+            burst.next_shot += definition.burst_interval;
+        }
+
+        if burst.remaining == 0 || magazine.is_some_and(|magazine| magazine.is_empty()) {
+            commands.entity(tank).remove::<Burst>();
+        }
+    }
+}